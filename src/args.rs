@@ -0,0 +1,124 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[command(version, about)]
+pub struct Args {
+	#[command(subcommand)]
+	pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+	/// Bump Yarn, Fabric Loader and Fabric API to the newest versions for the project's Minecraft version
+	Update {
+		/// Path to the project directory
+		#[arg(default_value = ".")]
+		path: PathBuf,
+
+		/// Apply the updates without asking for confirmation
+		#[arg(short, long)]
+		yes: bool,
+	},
+
+	/// Retarget a project to another Minecraft version
+	Port {
+		/// The Minecraft version to port the project to
+		minecraft_version: String,
+
+		/// Path to the project directory
+		#[arg(default_value = ".")]
+		path: PathBuf,
+
+		/// Apply the changes without asking for confirmation
+		#[arg(short, long)]
+		yes: bool,
+	},
+
+	/// Check the environment and a project for common setup problems
+	Doctor {
+		/// Path to the project directory
+		#[arg(default_value = ".")]
+		path: PathBuf,
+	},
+
+	/// Add a feature to an existing project
+	Add {
+		#[command(subcommand)]
+		feature: Feature,
+
+		/// Path to the project directory
+		#[arg(short, long, default_value = ".", global = true)]
+		path: PathBuf,
+	},
+
+	/// Show or change the defaults used when creating a project
+	Config {
+		#[command(subcommand)]
+		action: Option<ConfigAction>,
+	},
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigAction {
+	/// Set a default
+	Set { key: ConfigKey, value: String },
+
+	/// Remove a default, going back to the built-in one
+	Unset { key: ConfigKey },
+
+	/// Print the location of the config file
+	Path,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum ConfigKey {
+	Author,
+	MavenGroup,
+	License,
+	GradleDsl,
+	Git,
+	/// Whether to preselect the newest stable or newest Fabric Loader build
+	Loader,
+	HeapSize,
+	Parallel,
+	ConfigurationCache,
+	BuildCache,
+	Daemon,
+	Decompiler,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Feature {
+	/// A client entrypoint class
+	Client,
+
+	/// A mixin config with an example mixin
+	Mixin,
+
+	/// A data generation entrypoint and Loom's datagen setup
+	Datagen,
+
+	/// An access widener, wired up in fabric.mod.json and Loom
+	AccessWidener,
+
+	/// JUnit 5 with fabric-loader-junit and an example unit test
+	Junit,
+
+	/// A gametest source set with an example test and a Loom run config
+	Gametest,
+
+	/// A dependency on a mod published on Modrinth
+	Modrinth {
+		/// The project's slug or ID on Modrinth
+		slug: String,
+
+		/// The version to depend on, instead of the newest one for the project's Minecraft version
+		#[arg(long)]
+		version: Option<String>,
+
+		/// The dependency's mod ID, if it differs from its slug
+		#[arg(long)]
+		mod_id: Option<String>,
+	},
+}
@@ -0,0 +1,61 @@
+use crate::args::Feature;
+use crate::features;
+use crate::project::Project;
+use anyhow::Result;
+use crossterm::style::Stylize;
+use std::path::Path;
+
+pub fn run(feature: Feature, path: &Path) -> Result<()> {
+	let project = Project::open(path)?;
+	let info = project.mod_info()?;
+
+	let added = match feature {
+		Feature::Client => {
+			features::client::add(&project, &info)?;
+			"a client entrypoint".to_string()
+		}
+		Feature::Mixin => {
+			features::mixin::add(&project, &info)?;
+			"a mixin config".to_string()
+		}
+		Feature::Datagen => {
+			features::datagen::add(&project, &info)?;
+			"data generation".to_string()
+		}
+		Feature::AccessWidener => {
+			features::access_widener::add(&project, &info)?;
+			"an access widener".to_string()
+		}
+		Feature::Junit => {
+			features::junit::add(&project, &info)?;
+			"JUnit tests".to_string()
+		}
+		Feature::Gametest => {
+			features::gametest::add(&project, &info)?;
+			"gametests".to_string()
+		}
+		Feature::Modrinth {
+			slug,
+			version,
+			mod_id,
+		} => {
+			features::modrinth::add(
+				&project,
+				&info,
+				&slug,
+				version.as_deref(),
+				mod_id.as_deref(),
+			)?;
+			format!("a dependency on {}", slug)
+		}
+	};
+
+	println!(
+		"{}",
+		format!("✨ Added {} to {}.", added, info.mod_id)
+			.green()
+			.bold()
+	);
+
+	Ok(())
+}
@@ -0,0 +1,167 @@
+use crate::args::{ConfigAction, ConfigKey};
+use crate::config::{self, Config, LoaderChannel};
+use crate::input::{
+	gradle_dsl::GradleDsl,
+	gradle_settings::{validate_heap_size, Decompiler, GradleSettings},
+	license::validate_license,
+	maven_group::validate_maven_group,
+};
+use anyhow::{anyhow, bail, Context, Result};
+use clap::ValueEnum;
+use crossterm::style::Stylize;
+use inquire::validator::{ErrorMessage, Validation};
+use std::error::Error;
+
+pub fn run(action: Option<ConfigAction>) -> Result<()> {
+	let Some(action) = action else {
+		show(&Config::load()?);
+		return Ok(());
+	};
+
+	match action {
+		ConfigAction::Set { key, value } => {
+			let mut config = Config::load()?;
+			set(&mut config, key, value.trim())?;
+			config.save()?;
+			println!(
+				"{} {} = {}",
+				"✔".green(),
+				key_name(key),
+				get(&config, key).unwrap_or_default()
+			);
+		}
+		ConfigAction::Unset { key } => {
+			let mut config = Config::load()?;
+			unset(&mut config, key);
+			config.save()?;
+			println!("{} {} unset", "✔".green(), key_name(key));
+		}
+		ConfigAction::Path => println!("{}", config::path()?.display()),
+	}
+
+	Ok(())
+}
+
+fn show(config: &Config) {
+	let keys = ConfigKey::value_variants();
+	let width = keys
+		.iter()
+		.map(|&key| key_name(key).len())
+		.max()
+		.unwrap_or(0);
+
+	for &key in keys {
+		let value = match get(config, key) {
+			Some(value) => value.green(),
+			None => "not set".to_string().dark_grey(),
+		};
+		println!("{:<width$}  {}", key_name(key), value);
+	}
+}
+
+fn get(config: &Config, key: ConfigKey) -> Option<String> {
+	let gradle = &config.gradle;
+	match key {
+		ConfigKey::Author => config.author.clone(),
+		ConfigKey::MavenGroup => config.maven_group.clone(),
+		ConfigKey::License => config.license.clone(),
+		ConfigKey::GradleDsl => config.gradle_dsl.map(|dsl| dsl.to_string()),
+		ConfigKey::Git => config.git.map(|git| git.to_string()),
+		ConfigKey::Loader => config.loader.map(|loader| loader.to_string()),
+		ConfigKey::HeapSize => Some(gradle.heap_size.clone()),
+		ConfigKey::Parallel => Some(gradle.parallel.to_string()),
+		ConfigKey::ConfigurationCache => Some(gradle.configuration_cache.to_string()),
+		ConfigKey::BuildCache => Some(gradle.build_cache.to_string()),
+		ConfigKey::Daemon => Some(gradle.daemon.to_string()),
+		ConfigKey::Decompiler => Some(gradle.decompiler.to_string().to_lowercase()),
+	}
+}
+
+fn set(config: &mut Config, key: ConfigKey, value: &str) -> Result<()> {
+	let gradle = &mut config.gradle;
+	match key {
+		ConfigKey::Author => config.author = Some(value.to_string()),
+		ConfigKey::MavenGroup => {
+			check(validate_maven_group(value))?;
+			config.maven_group = Some(value.to_string());
+		}
+		ConfigKey::License => {
+			check(validate_license(value))?;
+			config.license = Some(value.to_string());
+		}
+		ConfigKey::GradleDsl => {
+			config.gradle_dsl = Some(match value.to_lowercase().as_str() {
+				"groovy" => GradleDsl::Groovy,
+				"kotlin" => GradleDsl::Kotlin,
+				_ => bail!("Gradle DSL must be 'groovy' or 'kotlin'"),
+			});
+		}
+		ConfigKey::Git => config.git = Some(parse_bool(value)?),
+		ConfigKey::Loader => {
+			config.loader = Some(match value.to_lowercase().as_str() {
+				"stable" => LoaderChannel::Stable,
+				"latest" => LoaderChannel::Latest,
+				_ => bail!("Loader must be 'stable' or 'latest'"),
+			});
+		}
+		ConfigKey::HeapSize => {
+			check(validate_heap_size(value))?;
+			gradle.heap_size = value.to_uppercase();
+		}
+		ConfigKey::Parallel => gradle.parallel = parse_bool(value)?,
+		ConfigKey::ConfigurationCache => gradle.configuration_cache = parse_bool(value)?,
+		ConfigKey::BuildCache => gradle.build_cache = parse_bool(value)?,
+		ConfigKey::Daemon => gradle.daemon = parse_bool(value)?,
+		ConfigKey::Decompiler => {
+			gradle.decompiler = Decompiler::ALL
+				.into_iter()
+				.find(|d| d.to_string().eq_ignore_ascii_case(value))
+				.context("Decompiler must be 'vineflower', 'cfr' or 'fernflower'")?;
+		}
+	}
+
+	Ok(())
+}
+
+fn unset(config: &mut Config, key: ConfigKey) {
+	let defaults = GradleSettings::default();
+	let gradle = &mut config.gradle;
+	match key {
+		ConfigKey::Author => config.author = None,
+		ConfigKey::MavenGroup => config.maven_group = None,
+		ConfigKey::License => config.license = None,
+		ConfigKey::GradleDsl => config.gradle_dsl = None,
+		ConfigKey::Git => config.git = None,
+		ConfigKey::Loader => config.loader = None,
+		ConfigKey::HeapSize => gradle.heap_size = defaults.heap_size,
+		ConfigKey::Parallel => gradle.parallel = defaults.parallel,
+		ConfigKey::ConfigurationCache => gradle.configuration_cache = defaults.configuration_cache,
+		ConfigKey::BuildCache => gradle.build_cache = defaults.build_cache,
+		ConfigKey::Daemon => gradle.daemon = defaults.daemon,
+		ConfigKey::Decompiler => gradle.decompiler = defaults.decompiler,
+	}
+}
+
+/// The key as it is typed on the command line.
+fn key_name(key: ConfigKey) -> String {
+	key.to_possible_value()
+		.map(|value| value.get_name().to_string())
+		.unwrap_or_default()
+}
+
+fn parse_bool(value: &str) -> Result<bool> {
+	match value.to_lowercase().as_str() {
+		"true" | "yes" | "on" => Ok(true),
+		"false" | "no" | "off" => Ok(false),
+		_ => bail!("Expected 'true' or 'false', got '{}'", value),
+	}
+}
+
+/// Turns the result of a prompt validator into an error for invalid values.
+fn check(validation: Result<Validation, Box<dyn Error + Send + Sync>>) -> Result<()> {
+	match validation.map_err(|e| anyhow!(e))? {
+		Validation::Valid => Ok(()),
+		Validation::Invalid(ErrorMessage::Custom(message)) => bail!(message),
+		Validation::Invalid(ErrorMessage::Default) => bail!("Invalid value"),
+	}
+}
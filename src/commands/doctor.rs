@@ -0,0 +1,367 @@
+use crate::input::icon::validate_icon;
+use crate::project::{dependency_versions::DependencyVersions, mod_json, Project};
+use crate::utils::get_java_version;
+use anyhow::{bail, Result};
+use crossterm::style::Stylize;
+use serde_json::Value;
+use std::{
+	env,
+	path::{Path, PathBuf},
+	process::Command,
+};
+
+#[derive(Debug, PartialEq)]
+enum Status {
+	Pass,
+	Warn,
+	Fail,
+}
+
+#[derive(Debug)]
+struct Check {
+	status: Status,
+	message: String,
+}
+
+impl Check {
+	fn pass(message: impl Into<String>) -> Self {
+		Self {
+			status: Status::Pass,
+			message: message.into(),
+		}
+	}
+
+	fn warn(message: impl Into<String>) -> Self {
+		Self {
+			status: Status::Warn,
+			message: message.into(),
+		}
+	}
+
+	fn fail(message: impl Into<String>) -> Self {
+		Self {
+			status: Status::Fail,
+			message: message.into(),
+		}
+	}
+
+	fn print(&self) {
+		let symbol = match self.status {
+			Status::Pass => "✔".green(),
+			Status::Warn => "⚠".yellow(),
+			Status::Fail => "✖".red(),
+		};
+		println!("  {} {}", symbol, self.message);
+	}
+}
+
+pub fn run(path: &Path) -> Result<()> {
+	let mut checks = Vec::new();
+
+	println!("\n{}", "🩺 Environment".cyan().bold());
+	let (jdk_check, jdk_major) = check_jdk();
+	let environment = [check_git(), jdk_check];
+	print_checks(&environment);
+	checks.extend(environment);
+
+	match Project::open(path) {
+		Ok(project) => {
+			println!("\n{}", "📦 Project".cyan().bold());
+			let project_checks = check_project(&project, jdk_major);
+			print_checks(&project_checks);
+			checks.extend(project_checks);
+		}
+		Err(e) => {
+			println!("\n{} {}", "Skipping project checks:".dark_grey(), e);
+		}
+	}
+
+	let failures = checks.iter().filter(|c| c.status == Status::Fail).count();
+	println!();
+	if failures > 0 {
+		bail!("{} check(s) failed", failures);
+	}
+
+	println!("{}", "✨ Everything looks good!".green().bold());
+	Ok(())
+}
+
+fn print_checks(checks: &[Check]) {
+	for check in checks {
+		check.print();
+	}
+}
+
+fn check_git() -> Check {
+	match command_output("git", &["--version"]) {
+		Some(output) => Check::pass(output.trim().to_string()),
+		None => Check::fail("git was not found on PATH"),
+	}
+}
+
+/// Finds `javac` the way Gradle does, preferring `JAVA_HOME` over `PATH`, and
+/// returns its major version alongside the check result.
+fn check_jdk() -> (Check, Option<u32>) {
+	let java_home = env::var_os("JAVA_HOME").map(PathBuf::from);
+	let javac = match &java_home {
+		Some(home) => {
+			let javac = home
+				.join("bin")
+				.join(format!("javac{}", env::consts::EXE_SUFFIX));
+			if !javac.is_file() {
+				return (
+					Check::fail(format!(
+						"JAVA_HOME is set to {}, which has no bin/javac; point it at a JDK, not a JRE",
+						home.display()
+					)),
+					None,
+				);
+			}
+			javac
+		}
+		None => PathBuf::from("javac"),
+	};
+
+	let Some(output) = command_output(&javac.to_string_lossy(), &["-version"]) else {
+		let message = match java_home {
+			Some(_) => format!("Could not run {}", javac.display()),
+			None => "No JDK found: javac is not on PATH and JAVA_HOME is not set".to_string(),
+		};
+		return (Check::fail(message), None);
+	};
+
+	let output = output.trim();
+	match parse_java_major(output) {
+		Some(major) => (
+			Check::pass(format!("JDK {} ({})", major, output)),
+			Some(major),
+		),
+		None => (
+			Check::warn(format!(
+				"Could not determine the JDK version from '{}'",
+				output
+			)),
+			None,
+		),
+	}
+}
+
+fn check_project(project: &Project, jdk_major: Option<u32>) -> Vec<Check> {
+	let mut checks = vec![
+		check_java_requirement(project, jdk_major),
+		check_gradlew(project),
+	];
+
+	match mod_json::load(&project.mod_json_path()) {
+		Ok(mod_json) => {
+			checks.push(check_license(&mod_json));
+			checks.push(check_icon(project, &mod_json));
+			checks.extend(check_mixin_configs(project, &mod_json));
+			checks.extend(check_entrypoints(project, &mod_json));
+		}
+		Err(e) => checks.push(Check::fail(format!("{:#}", e))),
+	}
+
+	checks
+}
+
+fn check_java_requirement(project: &Project, jdk_major: Option<u32>) -> Check {
+	let minecraft_version = DependencyVersions::load(project)
+		.and_then(|versions| versions.require("minecraft_version"));
+	let minecraft_version = match minecraft_version {
+		Ok(minecraft_version) => minecraft_version,
+		Err(e) => return Check::warn(format!("{:#}", e)),
+	};
+
+	let required = match get_java_version(&minecraft_version) {
+		Ok(required) => required,
+		Err(e) => return Check::warn(e.to_string()),
+	};
+	let required_major: u32 = required.parse().unwrap_or_default();
+
+	match jdk_major {
+		Some(installed) if installed >= required_major => Check::pass(format!(
+			"Minecraft {} needs Java {}, JDK {} is installed",
+			minecraft_version, required, installed
+		)),
+		Some(installed) => Check::fail(format!(
+			"Minecraft {} needs Java {}, but JDK {} is installed",
+			minecraft_version, required, installed
+		)),
+		None => Check::warn(format!(
+			"Minecraft {} needs Java {}, but the installed JDK version is unknown",
+			minecraft_version, required
+		)),
+	}
+}
+
+fn check_gradlew(project: &Project) -> Check {
+	let gradlew = project.dir.join("gradlew");
+	if !gradlew.is_file() {
+		return Check::fail("gradlew is missing");
+	}
+
+	if is_executable(&gradlew) {
+		Check::pass("gradlew is executable")
+	} else {
+		Check::fail("gradlew is not executable (run `chmod +x gradlew`)")
+	}
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+	use std::os::unix::fs::PermissionsExt;
+
+	path.metadata()
+		.map(|metadata| metadata.permissions().mode() & 0o111 != 0)
+		.unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+	true
+}
+
+fn check_license(mod_json: &Value) -> Check {
+	let licenses = match &mod_json["license"] {
+		Value::String(license) => vec![license.as_str()],
+		Value::Array(licenses) => licenses.iter().filter_map(Value::as_str).collect(),
+		_ => return Check::warn("fabric.mod.json doesn't declare a license"),
+	};
+
+	let invalid: Vec<_> = licenses
+		.iter()
+		.filter(|license| spdx::Expression::parse(license).is_err())
+		.collect();
+
+	if invalid.is_empty() {
+		Check::pass(format!(
+			"License {} is a valid SPDX expression",
+			licenses.join(", ")
+		))
+	} else {
+		Check::warn(format!(
+			"License {} is not a valid SPDX expression",
+			invalid
+				.iter()
+				.map(|l| format!("'{}'", l))
+				.collect::<Vec<_>>()
+				.join(", ")
+		))
+	}
+}
+
+fn check_icon(project: &Project, mod_json: &Value) -> Check {
+	let Some(icon) = mod_json["icon"].as_str() else {
+		return Check::warn("fabric.mod.json doesn't declare an icon");
+	};
+
+	let path = project.resources_dir().join(icon);
+	if !path.is_file() {
+		return check_resource(project, "Icon", icon);
+	}
+
+	match validate_icon(&path) {
+		Ok(size) => Check::pass(format!("Icon '{}' is {}x{}", icon, size, size)),
+		Err(e) => Check::warn(format!("Icon '{}': {:#}", icon, e)),
+	}
+}
+
+fn check_mixin_configs(project: &Project, mod_json: &Value) -> Vec<Check> {
+	mod_json::mixin_configs(mod_json)
+		.iter()
+		.map(|config| check_resource(project, "Mixin config", config))
+		.collect()
+}
+
+fn check_resource(project: &Project, kind: &str, resource: &str) -> Check {
+	if project.resources_dir().join(resource).is_file() {
+		Check::pass(format!("{} '{}' exists", kind, resource))
+	} else {
+		Check::fail(format!(
+			"{} '{}' is referenced from fabric.mod.json but missing",
+			kind, resource
+		))
+	}
+}
+
+fn check_entrypoints(project: &Project, mod_json: &Value) -> Vec<Check> {
+	let Some(entrypoints) = mod_json["entrypoints"].as_object() else {
+		return Vec::new();
+	};
+
+	entrypoints
+		.iter()
+		.flat_map(|(kind, values)| {
+			values
+				.as_array()
+				.into_iter()
+				.flatten()
+				.filter_map(|value| value.as_str().or_else(|| value["value"].as_str()))
+				.map(move |reference| (kind, reference))
+		})
+		.map(|(kind, reference)| {
+			let class = entrypoint_class(reference);
+			if find_source_file(project, class).is_some() {
+				Check::pass(format!("Entrypoint '{}' ({}) exists", class, kind))
+			} else {
+				Check::fail(format!(
+					"Entrypoint '{}' ({}) has no matching source file",
+					class, kind
+				))
+			}
+		})
+		.collect()
+}
+
+/// Strips member references (`Class::field`) and nested classes (`Outer$Inner`).
+fn entrypoint_class(reference: &str) -> &str {
+	let class = reference.split("::").next().unwrap_or(reference);
+	class.split('$').next().unwrap_or(class)
+}
+
+/// Looks for the class in every `src/<source set>/{java,kotlin}` directory.
+fn find_source_file(project: &Project, class: &str) -> Option<PathBuf> {
+	let relative = class.replace('.', "/");
+	let source_sets = project.dir.join("src").read_dir().ok()?;
+
+	source_sets
+		.filter_map(|entry| entry.ok())
+		.flat_map(|entry| {
+			[
+				entry.path().join("java").join(format!("{}.java", relative)),
+				entry.path().join("kotlin").join(format!("{}.kt", relative)),
+			]
+		})
+		.find(|path| path.is_file())
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+	let output = Command::new(program).args(args).output().ok()?;
+	if !output.status.success() {
+		return None;
+	}
+
+	// Older JDKs print their version to stderr.
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	let text = if stdout.trim().is_empty() {
+		String::from_utf8_lossy(&output.stderr).into_owned()
+	} else {
+		stdout.into_owned()
+	};
+
+	Some(text)
+}
+
+/// Parses the major version out of `javac 21.0.2` or `javac 1.8.0_392`.
+fn parse_java_major(output: &str) -> Option<u32> {
+	let version = output.split_whitespace().nth(1)?;
+	let mut parts = version.split(['.', '_', '-']);
+	let first: u32 = parts.next()?.parse().ok()?;
+
+	if first == 1 {
+		parts.next()?.parse().ok()
+	} else {
+		Some(first)
+	}
+}
@@ -0,0 +1,65 @@
+pub mod add;
+pub mod config;
+pub mod doctor;
+pub mod port;
+pub mod update;
+
+use crate::utils::parse_version;
+use crossterm::style::Stylize;
+
+/// A dependency version as it is in a project and as it would be after a change.
+#[derive(Debug)]
+pub struct VersionChange {
+	pub name: &'static str,
+	pub key: &'static str,
+	pub current: String,
+	pub target: String,
+}
+
+impl VersionChange {
+	pub fn is_change(&self) -> bool {
+		self.current != self.target
+	}
+
+	/// Whether `target` is strictly newer than `current`. Versions that can't
+	/// be compared are treated as newer whenever they differ.
+	pub fn is_upgrade(&self) -> bool {
+		match (parse_version(&self.current), parse_version(&self.target)) {
+			(Some(current), Some(target)) => target > current,
+			_ => self.is_change(),
+		}
+	}
+}
+
+pub fn print_version_table(changes: &[VersionChange], target_header: &str) {
+	let name_width = column_width("Dependency", changes.iter().map(|c| c.name));
+	let current_width = column_width("Current", changes.iter().map(|c| c.current.as_str()));
+
+	println!(
+		"\n{}",
+		format!(
+			"{:<name_width$}  {:<current_width$}  {}",
+			"Dependency", "Current", target_header
+		)
+		.bold()
+	);
+
+	for change in changes {
+		let target = if change.is_change() {
+			change.target.as_str().green().to_string()
+		} else {
+			change.target.as_str().dark_grey().to_string()
+		};
+
+		println!(
+			"{:<name_width$}  {:<current_width$}  {}",
+			change.name, change.current, target
+		);
+	}
+
+	println!();
+}
+
+fn column_width<'a>(header: &str, values: impl Iterator<Item = &'a str>) -> usize {
+	values.map(str::len).max().unwrap_or(0).max(header.len())
+}
@@ -0,0 +1,232 @@
+use super::{print_version_table, VersionChange};
+use crate::input::{minecraft_range::MinecraftRange, render_config};
+use crate::project::{
+	build_script::BuildScript, dependency_versions::DependencyVersions, mod_json,
+	properties::GradleProperties, Project,
+};
+use crate::utils::{get_gradle_version, get_java_version, get_loom_version};
+use crate::versions::VersionManager;
+use anyhow::{Context, Result};
+use crossterm::style::Stylize;
+use inquire::Confirm;
+use serde_json::Value;
+use std::path::Path;
+
+pub fn run(minecraft_version: &str, path: &Path, yes: bool) -> Result<()> {
+	let mut project = Project::open(path)?;
+	let wrapper_path = project.wrapper_properties_path();
+	let mut versions = DependencyVersions::load(&project)?;
+	let mut wrapper = GradleProperties::load(&wrapper_path)?;
+	let mut build_script = BuildScript::load(&project.build_script_path())?;
+
+	let current_minecraft = versions.require("minecraft_version")?;
+	// Checked before any lookups, so unsupported versions fail fast.
+	let java_version = get_java_version(minecraft_version)?;
+	let loom_version = get_loom_version(minecraft_version)?;
+	let gradle_version = get_gradle_version(minecraft_version)?;
+
+	println!(
+		"{}",
+		format!(
+			"🔍 Looking up versions for Minecraft {}...",
+			minecraft_version
+		)
+		.cyan()
+		.bold()
+	);
+
+	let version_manager = VersionManager::new();
+	let yarn_version = version_manager
+		.get_compatible_yarn_versions(minecraft_version)?
+		.into_iter()
+		.next()
+		.with_context(|| {
+			format!(
+				"No Yarn mappings are available for Minecraft {}",
+				minecraft_version
+			)
+		})?;
+	let fabric_api_version = version_manager
+		.get_compatible_fabric_api_versions(minecraft_version)?
+		.into_iter()
+		.next()
+		.with_context(|| {
+			format!(
+				"No Fabric API release is available for Minecraft {}",
+				minecraft_version
+			)
+		})?;
+
+	let changes = [
+		VersionChange {
+			name: "Minecraft",
+			key: "minecraft_version",
+			current: current_minecraft.clone(),
+			target: minecraft_version.to_string(),
+		},
+		VersionChange {
+			name: "Yarn",
+			key: "yarn_mappings",
+			current: versions.require("yarn_mappings")?,
+			target: yarn_version,
+		},
+		VersionChange {
+			name: "Fabric Loader",
+			key: "fabric_loader_version",
+			current: versions.require("fabric_loader_version")?,
+			target: version_manager.get_latest_loader_version()?,
+		},
+		VersionChange {
+			name: "Fabric API",
+			key: "fabric_api_version",
+			current: versions.require("fabric_api_version")?,
+			target: fabric_api_version,
+		},
+		VersionChange {
+			name: "Loom",
+			key: "loom",
+			current: versions
+				.get("loom")
+				.or(build_script.loom_version())
+				.unwrap_or("unknown")
+				.to_string(),
+			target: loom_version.to_string(),
+		},
+		VersionChange {
+			name: "Gradle",
+			key: "gradle",
+			current: wrapper
+				.get("distributionUrl")
+				.and_then(gradle_distribution_version)
+				.unwrap_or("unknown")
+				.to_string(),
+			target: gradle_version.to_string(),
+		},
+		VersionChange {
+			name: "Java",
+			key: "java",
+			current: match build_script.java_version() {
+				Some(version) => version.to_string(),
+				None => get_java_version(&current_minecraft)?.to_string(),
+			},
+			target: java_version.to_string(),
+		},
+	];
+
+	print_version_table(&changes, "Target");
+
+	if !changes.iter().any(VersionChange::is_change) {
+		println!(
+			"{}",
+			format!(
+				"✨ The project already targets Minecraft {}.",
+				minecraft_version
+			)
+			.green()
+			.bold()
+		);
+		return Ok(());
+	}
+
+	let apply = yes
+		|| Confirm::new(
+			&format!("Port the project to Minecraft {}?", minecraft_version)
+				.bold()
+				.to_string(),
+		)
+		.with_default(true)
+		.with_render_config(render_config())
+		.prompt()?;
+	if !apply {
+		return Ok(());
+	}
+
+	for change in changes.iter().filter(|c| c.is_change()) {
+		match change.key {
+			"loom" if versions.is_catalog() => versions.set("loom", &change.target),
+			"loom" => build_script.set_loom_version(&change.target),
+			"java" => build_script.set_java_version(&change.target),
+			"gradle" => set_gradle_version(&mut wrapper, &change.current, &change.target),
+			key => versions.set(key, &change.target),
+		}
+	}
+
+	versions.save()?;
+	wrapper.save(&wrapper_path)?;
+	build_script.save()?;
+	update_mod_json(&project, minecraft_version, java_version)?;
+
+	if let Some(manifest) = &mut project.manifest {
+		manifest.sync_versions(&versions);
+		manifest.save(&project.dir)?;
+	}
+
+	println!(
+		"{}",
+		format!("✨ Project ported to Minecraft {}.", minecraft_version)
+			.green()
+			.bold()
+	);
+	println!("Run `./gradlew build` to check which parts of your mod need updating.");
+
+	Ok(())
+}
+
+/// Extracts `8.9` from a distribution URL like `.../gradle-8.9-bin.zip`.
+fn gradle_distribution_version(url: &str) -> Option<&str> {
+	let start = url.rfind("gradle-")? + "gradle-".len();
+	let end = url.rfind('-')?;
+
+	(end > start).then(|| &url[start..end])
+}
+
+fn set_gradle_version(wrapper: &mut GradleProperties, current: &str, target: &str) {
+	let url = match wrapper.get("distributionUrl") {
+		Some(url) if url.contains(&format!("gradle-{}-", current)) => url.replacen(
+			&format!("gradle-{}-", current),
+			&format!("gradle-{}-", target),
+			1,
+		),
+		_ => format!(
+			"https\\://services.gradle.org/distributions/gradle-{}-bin.zip",
+			target
+		),
+	};
+
+	wrapper.set("distributionUrl", &url);
+}
+
+/// Updates the `minecraft` and `java` dependency ranges, and the compatibility
+/// level of any mixin configs that declare one.
+fn update_mod_json(project: &Project, minecraft_version: &str, java_version: &str) -> Result<()> {
+	let mod_json_path = project.mod_json_path();
+	let mut mod_json = mod_json::load(&mod_json_path)?;
+
+	// Keep the range style the project was created with. Custom ranges can't be
+	// carried over to another version, so those fall back to a patch range.
+	let range = match project.manifest.as_ref().map(|m| &m.input.minecraft_range) {
+		Some(MinecraftRange::Exact) => MinecraftRange::Exact,
+		Some(MinecraftRange::Wildcard) => MinecraftRange::Wildcard,
+		_ => MinecraftRange::Patch,
+	};
+	let range = serde_json::to_value(range.range(minecraft_version))?;
+	mod_json::child_object(&mut mod_json, "depends")?.insert("minecraft".to_string(), range);
+	mod_json::add_dependency(&mut mod_json, "java", &format!(">={}", java_version))?;
+
+	mod_json::save(&mod_json_path, &mod_json)?;
+
+	for config in mod_json::mixin_configs(&mod_json) {
+		let config_path = project.resources_dir().join(&config);
+		if !config_path.is_file() {
+			continue;
+		}
+
+		let mut mixin_config = mod_json::load(&config_path)?;
+		if let Some(level) = mixin_config.get_mut("compatibilityLevel") {
+			*level = Value::String(format!("JAVA_{}", java_version));
+			mod_json::save(&config_path, &mixin_config)?;
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,101 @@
+use super::{print_version_table, VersionChange};
+use crate::input::render_config;
+use crate::project::{dependency_versions::DependencyVersions, Project};
+use crate::versions::VersionManager;
+use anyhow::Result;
+use crossterm::style::Stylize;
+use inquire::Confirm;
+use std::path::Path;
+
+pub fn run(path: &Path, yes: bool) -> Result<()> {
+	let mut project = Project::open(path)?;
+	let mut versions = DependencyVersions::load(&project)?;
+	let minecraft_version = versions.require("minecraft_version")?;
+
+	println!(
+		"{}",
+		format!(
+			"🔍 Checking for updates for Minecraft {}...",
+			minecraft_version
+		)
+		.cyan()
+		.bold()
+	);
+
+	let version_manager = VersionManager::new();
+	let latest = [
+		(
+			"Yarn",
+			"yarn_mappings",
+			version_manager
+				.get_compatible_yarn_versions(&minecraft_version)?
+				.into_iter()
+				.next(),
+		),
+		(
+			"Fabric Loader",
+			"fabric_loader_version",
+			Some(version_manager.get_latest_loader_version()?),
+		),
+		(
+			"Fabric API",
+			"fabric_api_version",
+			version_manager
+				.get_compatible_fabric_api_versions(&minecraft_version)?
+				.into_iter()
+				.next(),
+		),
+	];
+
+	let mut changes = Vec::new();
+	for (name, key, available) in latest {
+		let current = versions.require(key)?;
+
+		let mut change = VersionChange {
+			name,
+			key,
+			target: available.unwrap_or_else(|| current.clone()),
+			current,
+		};
+		// Never downgrade a version that was bumped by hand past what we know about.
+		if !change.is_upgrade() {
+			change.target = change.current.clone();
+		}
+		changes.push(change);
+	}
+
+	print_version_table(&changes, "Available");
+
+	if !changes.iter().any(VersionChange::is_change) {
+		println!("{}", "✨ Everything is already up to date.".green().bold());
+		return Ok(());
+	}
+
+	let apply = yes
+		|| Confirm::new(&"Apply these updates?".bold().to_string())
+			.with_default(true)
+			.with_render_config(render_config())
+			.prompt()?;
+	if !apply {
+		return Ok(());
+	}
+
+	for change in changes.iter().filter(|c| c.is_change()) {
+		versions.set(change.key, &change.target);
+	}
+	versions.save()?;
+
+	if let Some(manifest) = &mut project.manifest {
+		manifest.sync_versions(&versions);
+		manifest.save(&project.dir)?;
+	}
+
+	println!(
+		"{}",
+		format!("✨ {} updated.", versions.file_name())
+			.green()
+			.bold()
+	);
+
+	Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::input::{gradle_dsl::GradleDsl, gradle_settings::GradleSettings};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, path::PathBuf};
+
+/// Per-user defaults, kept in `modweave/config.toml` in the platform's config directory.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Config {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub author: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub maven_group: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub license: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub gradle_dsl: Option<GradleDsl>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub git: Option<bool>,
+	/// Which Fabric Loader build is preselected in the version list.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub loader: Option<LoaderChannel>,
+	#[serde(default)]
+	pub gradle: GradleSettings,
+}
+
+impl Config {
+	/// Loads the config, falling back to the built-in defaults if there is none yet.
+	pub fn load() -> Result<Self> {
+		let path = path()?;
+		if !path.is_file() {
+			return Ok(Self::default());
+		}
+
+		let content = fs::read_to_string(&path)
+			.with_context(|| format!("Failed to read {}", path.display()))?;
+		toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+	}
+
+	pub fn save(&self) -> Result<()> {
+		let path = path()?;
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)
+				.with_context(|| format!("Failed to create {}", parent.display()))?;
+		}
+
+		let content = toml::to_string_pretty(self).context("Failed to serialize config")?;
+		fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
+	}
+}
+
+/// Which builds of Fabric Loader to prefer. A channel rather than a version,
+/// so the preference doesn't go stale as new builds are published.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LoaderChannel {
+	/// The newest stable build.
+	#[default]
+	Stable,
+	/// The newest build, including betas.
+	Latest,
+}
+
+impl fmt::Display for LoaderChannel {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			LoaderChannel::Stable => "stable",
+			LoaderChannel::Latest => "latest",
+		})
+	}
+}
+
+pub fn path() -> Result<PathBuf> {
+	dirs::config_dir()
+		.map(|dir| dir.join("modweave").join("config.toml"))
+		.context("Failed to find the user config directory")
+}
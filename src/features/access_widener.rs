@@ -0,0 +1,36 @@
+use super::write_new_file;
+use crate::project::{build_script::BuildScript, mod_json, ModInfo, Project};
+use crate::scaffold::{read_snippet, read_template};
+use anyhow::{bail, Context, Result};
+use serde_json::json;
+
+/// The namespace access wideners are written in; Yarn's development names.
+const NAMESPACE: &str = "named";
+
+pub fn add(project: &Project, info: &ModInfo) -> Result<()> {
+	let mod_json_path = project.mod_json_path();
+	let mut mod_json = mod_json::load(&mod_json_path)?;
+
+	if mod_json.get("accessWidener").is_some() {
+		bail!("{} already has an access widener", info.mod_id);
+	}
+
+	let file_name = format!("{}.accesswidener", info.mod_id);
+	let content = read_template("fabric/mod.accesswidener")
+		.context("Failed to read access widener template")?
+		.replace("${{ namespace }}", NAMESPACE);
+	write_new_file(&project.resources_dir().join(&file_name), &content)?;
+
+	mod_json::object_mut(&mut mod_json)?.insert("accessWidener".into(), json!(file_name));
+	mod_json::save(&mod_json_path, &mod_json)?;
+
+	let mut build_script = BuildScript::load(&project.build_script_path())?;
+	if !build_script.contains("accessWidenerPath") {
+		let snippet = read_snippet(project.gradle_dsl(), "access_widener")?
+			.replace("${{ access_widener }}", &file_name);
+		build_script.append(&snippet);
+		build_script.save()?;
+	}
+
+	Ok(())
+}
@@ -0,0 +1,23 @@
+use super::{render_class, write_class};
+use crate::project::{mod_json, ModInfo, Project};
+use anyhow::{bail, Result};
+
+pub fn add(project: &Project, info: &ModInfo) -> Result<()> {
+	let mod_json_path = project.mod_json_path();
+	let mut mod_json = mod_json::load(&mod_json_path)?;
+
+	if mod_json::has_entrypoint(&mod_json, "client") {
+		bail!("{} already has a client entrypoint", info.mod_id);
+	}
+
+	let class_name = format!("{}Client", info.class_name);
+	let content = render_class("ClientClass.java.template", info)?;
+	write_class(project, info, "client", &class_name, &content)?;
+
+	mod_json::add_entrypoint(
+		&mut mod_json,
+		"client",
+		&format!("{}.client.{}", info.package, class_name),
+	)?;
+	mod_json::save(&mod_json_path, &mod_json)
+}
@@ -0,0 +1,51 @@
+use super::{render_class, write_class};
+use crate::project::{build_script::BuildScript, mod_json, ModInfo, Project};
+use crate::scaffold::read_snippet;
+use crate::utils::{create_directory, release_version};
+use anyhow::{bail, Result};
+use semver::Version;
+
+/// `FabricDataGenerator.createPack()`, which the generated entrypoint uses,
+/// was added with Fabric API's datagen rewrite for 1.19.3.
+const MIN_MINECRAFT_VERSION: Version = Version::new(1, 19, 3);
+
+pub fn is_supported(minecraft_version: &str) -> bool {
+	release_version(minecraft_version).is_ok_and(|version| version >= MIN_MINECRAFT_VERSION)
+}
+
+pub fn add(project: &Project, info: &ModInfo) -> Result<()> {
+	if !is_supported(&info.minecraft_version) {
+		bail!(
+			"Data generation needs Minecraft {} or newer, but {} targets {}",
+			MIN_MINECRAFT_VERSION,
+			info.mod_id,
+			info.minecraft_version
+		);
+	}
+
+	let mod_json_path = project.mod_json_path();
+	let mut mod_json = mod_json::load(&mod_json_path)?;
+
+	if mod_json::has_entrypoint(&mod_json, "fabric-datagen") {
+		bail!("{} already has a fabric-datagen entrypoint", info.mod_id);
+	}
+
+	let class_name = format!("{}DataGenerator", info.class_name);
+	let content = render_class("DataGenerator.java.template", info)?;
+	write_class(project, info, "datagen", &class_name, &content)?;
+
+	mod_json::add_entrypoint(
+		&mut mod_json,
+		"fabric-datagen",
+		&format!("{}.datagen.{}", info.package, class_name),
+	)?;
+	mod_json::save(&mod_json_path, &mod_json)?;
+
+	let mut build_script = BuildScript::load(&project.build_script_path())?;
+	if !build_script.contains("configureDataGeneration") {
+		build_script.append(&read_snippet(project.gradle_dsl(), "datagen")?);
+		build_script.save()?;
+	}
+
+	create_directory(&project.dir.join("src").join("main").join("generated"))
+}
@@ -0,0 +1,79 @@
+use super::{render_class, write_new_file, write_source_set_class};
+use crate::project::{
+	build_script::BuildScript,
+	mod_json::{self, ModJson},
+	ModInfo, Project,
+};
+use crate::scaffold::{read_snippet, read_template};
+use crate::utils::{release_version, MAX_MOD_ID_LENGTH};
+use anyhow::{bail, Context, Result};
+use indexmap::IndexMap;
+use semver::Version;
+
+const SOURCE_SET: &str = "gametest";
+const ID_SUFFIX: &str = "-gametest";
+
+pub fn add(project: &Project, info: &ModInfo) -> Result<()> {
+	let source_set_dir = project.source_set_dir(SOURCE_SET);
+	if source_set_dir.exists() {
+		bail!("{} already has a gametest source set", info.mod_id);
+	}
+
+	let minecraft_version = release_version(&info.minecraft_version)?;
+
+	// Fabric API replaced `FabricGameTest` with its own `@GameTest` annotation in 1.21.5.
+	let template = if minecraft_version >= Version::new(1, 21, 5) {
+		"GameTest.java.template"
+	} else {
+		"GameTestLegacy.java.template"
+	};
+	let class_name = format!("{}GameTest", info.class_name);
+	let content = render_class(template, info)?;
+	write_source_set_class(project, SOURCE_SET, info, "gametest", &class_name, &content)?;
+
+	// Like other data folders, the structure folder became singular in 1.21.
+	let structures = if minecraft_version >= Version::new(1, 21, 0) {
+		"structure"
+	} else {
+		"structures"
+	};
+	let resources_dir = source_set_dir.join("resources");
+	write_new_file(
+		&resources_dir
+			.join("data")
+			.join(&info.mod_id)
+			.join("gametest")
+			.join(structures)
+			.join("example.snbt"),
+		&read_template("fabric/gametest.snbt").context("Failed to read gametest structure")?,
+	)?;
+
+	// The tests are a separate mod, so they never end up in the main jar. Long
+	// mod IDs are shortened to keep its ID within Fabric's limit.
+	let base_id: String = info
+		.mod_id
+		.chars()
+		.take(MAX_MOD_ID_LENGTH - ID_SUFFIX.len())
+		.collect();
+	let gametest_id = format!("{}{}", base_id, ID_SUFFIX);
+	let metadata = ModJson {
+		entrypoints: IndexMap::from([(
+			"fabric-gametest".to_string(),
+			vec![format!("{}.gametest.{}", info.package, class_name)],
+		)]),
+		depends: IndexMap::from([(info.mod_id.clone(), "*".into())]),
+		..ModJson::new(
+			&gametest_id,
+			"1.0.0",
+			&format!("{} Game Tests", info.class_name),
+		)
+	};
+	mod_json::save(&resources_dir.join("fabric.mod.json"), &metadata)?;
+
+	let mut build_script = BuildScript::load(&project.build_script_path())?;
+	let snippet = read_snippet(project.gradle_dsl(), "gametest")?
+		.replace("${{ mod_id }}", &info.mod_id)
+		.replace("${{ gametest_id }}", &gametest_id);
+	build_script.append(&snippet);
+	build_script.save()
+}
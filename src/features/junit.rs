@@ -0,0 +1,51 @@
+use super::{render_class, write_source_set_class};
+use crate::input::gradle_dsl::GradleDsl;
+use crate::project::{
+	build_script::BuildScript, catalog, dependency_versions::DependencyVersions, ModInfo, Project,
+};
+use crate::scaffold::read_snippet;
+use anyhow::{bail, Result};
+
+/// Named so it doesn't share the `fabric-loader` accessor's path.
+const CATALOG_ALIAS: &str = "fabric-junit";
+
+pub fn add(project: &Project, info: &ModInfo) -> Result<()> {
+	let mut build_script = BuildScript::load(&project.build_script_path())?;
+
+	if build_script.contains("fabric-loader-junit")
+		|| build_script.contains(&catalog::accessor(CATALOG_ALIAS))
+	{
+		bail!("{} already depends on fabric-loader-junit", info.mod_id);
+	}
+
+	let class_name = format!("{}Test", info.class_name);
+	let content = render_class("UnitTest.java.template", info)?;
+	write_source_set_class(project, "test", info, "", &class_name, &content)?;
+
+	let mut versions = DependencyVersions::load(project)?;
+	let dependency = match (&mut versions, project.gradle_dsl()) {
+		(DependencyVersions::Catalog { catalog, .. }, dsl) => {
+			catalog.add_library(CATALOG_ALIAS, "net.fabricmc:fabric-loader-junit", "fabric-loader");
+			match dsl {
+				GradleDsl::Groovy => format!("testImplementation {}", catalog::accessor(CATALOG_ALIAS)),
+				GradleDsl::Kotlin => format!("testImplementation({})", catalog::accessor(CATALOG_ALIAS)),
+			}
+		}
+		(DependencyVersions::Properties { .. }, GradleDsl::Groovy) => {
+			"testImplementation \"net.fabricmc:fabric-loader-junit:${project.fabric_loader_version}\""
+				.to_string()
+		}
+		(DependencyVersions::Properties { .. }, GradleDsl::Kotlin) => {
+			"testImplementation(\"net.fabricmc:fabric-loader-junit:${project.property(\"fabric_loader_version\")}\")"
+				.to_string()
+		}
+	};
+	build_script.insert_into_block("dependencies", &dependency);
+	versions.save()?;
+
+	if !build_script.contains("useJUnitPlatform") {
+		build_script.append(&read_snippet(project.gradle_dsl(), "junit")?);
+	}
+
+	build_script.save()
+}
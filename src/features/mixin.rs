@@ -0,0 +1,29 @@
+use super::{class_path, ensure_new_file, render_class, write_new_file};
+use crate::project::{mod_json, ModInfo, Project};
+use crate::scaffold::read_template;
+use crate::utils::get_java_version;
+use anyhow::{Context, Result};
+
+pub fn add(project: &Project, info: &ModInfo) -> Result<()> {
+	let config = format!("{}.mixins.json", info.mod_id);
+	let config_path = project.resources_dir().join(&config);
+	let example_path = class_path(project, "main", info, "mixin", "ExampleMixin");
+	ensure_new_file(&config_path)?;
+	ensure_new_file(&example_path)?;
+
+	let mod_json_path = project.mod_json_path();
+	let mut mod_json = mod_json::load(&mod_json_path)?;
+	mod_json::add_mixin_config(&mut mod_json, &config)?;
+
+	let java_version = get_java_version(&info.minecraft_version)?;
+	let content = read_template("fabric/mod.mixin.json")
+		.context("Failed to read mixin config template")?
+		.replace("${{ package }}", &info.package)
+		.replace("${{ java_version }}", java_version);
+	let example = render_class("ExampleMixin.java.template", info)?;
+
+	// Nothing is written until everything above has succeeded.
+	write_new_file(&config_path, &content)?;
+	write_new_file(&example_path, &example)?;
+	mod_json::save(&mod_json_path, &mod_json)
+}
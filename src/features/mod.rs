@@ -0,0 +1,84 @@
+pub mod access_widener;
+pub mod client;
+pub mod datagen;
+pub mod gametest;
+pub mod junit;
+pub mod mixin;
+pub mod modrinth;
+
+use crate::project::{ModInfo, Project};
+use crate::scaffold::read_template;
+use crate::utils::create_directory;
+use anyhow::{bail, Context, Result};
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
+
+/// Renders one of the `fabric/java` class templates for the mod.
+fn render_class(template: &str, info: &ModInfo) -> Result<String> {
+	Ok(read_template(&format!("fabric/java/{}", template))?
+		.replace("${PACKAGE}", &info.package)
+		.replace("${CLASS_NAME}", &info.class_name)
+		.replace("${MOD_ID}", &info.mod_id))
+}
+
+/// Writes a class into a subpackage of the mod's main package.
+fn write_class(
+	project: &Project,
+	info: &ModInfo,
+	subpackage: &str,
+	class_name: &str,
+	content: &str,
+) -> Result<()> {
+	write_source_set_class(project, "main", info, subpackage, class_name, content)
+}
+
+/// Writes a class into a subpackage of the mod's package, in another source set
+/// than `main`. An empty `subpackage` puts it into the package itself.
+fn write_source_set_class(
+	project: &Project,
+	source_set: &str,
+	info: &ModInfo,
+	subpackage: &str,
+	class_name: &str,
+	content: &str,
+) -> Result<()> {
+	write_new_file(
+		&class_path(project, source_set, info, subpackage, class_name),
+		content,
+	)
+}
+
+fn class_path(
+	project: &Project,
+	source_set: &str,
+	info: &ModInfo,
+	subpackage: &str,
+	class_name: &str,
+) -> PathBuf {
+	project
+		.source_set_dir(source_set)
+		.join("java")
+		.join(info.package.replace('.', "/"))
+		.join(subpackage)
+		.join(format!("{}.java", class_name))
+}
+
+fn ensure_new_file(path: &Path) -> Result<()> {
+	if path.exists() {
+		bail!("{} already exists", path.display());
+	}
+
+	Ok(())
+}
+
+fn write_new_file(path: &Path, content: &str) -> Result<()> {
+	ensure_new_file(path)?;
+
+	if let Some(parent) = path.parent() {
+		create_directory(parent)?;
+	}
+
+	fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
@@ -0,0 +1,82 @@
+use crate::input::gradle_dsl::GradleDsl;
+use crate::project::{
+	build_script::BuildScript, catalog, dependency_versions::DependencyVersions, mod_json, ModInfo,
+	Project,
+};
+use crate::versions::VersionManager;
+use anyhow::{bail, Result};
+
+const MODRINTH_MAVEN: &str = "https://api.modrinth.com/maven";
+
+/// Adds a mod from Modrinth's Maven as a dependency. The version defaults to
+/// the newest Fabric release for the project's Minecraft version.
+pub fn add(
+	project: &Project,
+	info: &ModInfo,
+	slug: &str,
+	version: Option<&str>,
+	mod_id: Option<&str>,
+) -> Result<()> {
+	let alias = catalog::alias(slug);
+	let mut build_script = BuildScript::load(&project.build_script_path())?;
+	let mut versions = DependencyVersions::load(project)?;
+	let mod_json_path = project.mod_json_path();
+	let mut mod_json = mod_json::load(&mod_json_path)?;
+	let already_added = match &versions {
+		DependencyVersions::Catalog { catalog, .. } => catalog.contains_library(&alias),
+		DependencyVersions::Properties { .. } => {
+			build_script.contains(&format!("maven.modrinth:{}:", slug))
+		}
+	};
+	if already_added {
+		bail!("{} already depends on {}", info.mod_id, slug);
+	}
+
+	let version = match version {
+		Some(version) => version.to_string(),
+		None => VersionManager::new().get_latest_modrinth_version(slug, &info.minecraft_version)?,
+	};
+
+	// With a version catalog the dependency is declared there, under an alias
+	// derived from the slug.
+	let property = match &mut versions {
+		DependencyVersions::Catalog { catalog, .. } => {
+			catalog.set_version(&alias, &version);
+			catalog.add_library(&alias, &format!("maven.modrinth:{}", slug), &alias);
+			None
+		}
+		DependencyVersions::Properties { properties, .. } => {
+			let property = format!("{}_version", alias.replace('-', "_"));
+			properties.set(&property, &version);
+			Some(property)
+		}
+	};
+
+	let repository = match project.gradle_dsl() {
+		GradleDsl::Groovy => format!("maven {{ url \"{}\" }}", MODRINTH_MAVEN),
+		GradleDsl::Kotlin => format!("maven(\"{}\")", MODRINTH_MAVEN),
+	};
+	let dependency = match (project.gradle_dsl(), property) {
+		(GradleDsl::Groovy, None) => format!("modImplementation {}", catalog::accessor(&alias)),
+		(GradleDsl::Kotlin, None) => format!("modImplementation({})", catalog::accessor(&alias)),
+		(GradleDsl::Groovy, Some(property)) => format!(
+			"modImplementation \"maven.modrinth:{}:${{project.{}}}\"",
+			slug, property
+		),
+		(GradleDsl::Kotlin, Some(property)) => format!(
+			"modImplementation(\"maven.modrinth:{}:${{project.property(\"{}\")}}\")",
+			slug, property
+		),
+	};
+
+	if !build_script.contains(MODRINTH_MAVEN) {
+		build_script.insert_into_block("repositories", &repository);
+	}
+	build_script.insert_into_block("dependencies", &dependency);
+	mod_json::add_dependency(&mut mod_json, mod_id.unwrap_or(slug), "*")?;
+
+	// Nothing is written until every file has been loaded and updated.
+	build_script.save()?;
+	versions.save()?;
+	mod_json::save(&mod_json_path, &mod_json)
+}
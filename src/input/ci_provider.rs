@@ -0,0 +1,47 @@
+use anyhow::Result;
+use crossterm::style::{style, Stylize};
+use inquire::{ui::RenderConfig, Select};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CiProvider {
+	#[default]
+	None,
+	GitHub,
+	GitLab,
+	Forgejo,
+}
+
+impl CiProvider {
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			CiProvider::None => "none",
+			CiProvider::GitHub => "GitHub Actions",
+			CiProvider::GitLab => "GitLab CI",
+			CiProvider::Forgejo => "Forgejo Actions",
+		}
+	}
+}
+
+impl fmt::Display for CiProvider {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+pub fn prompt(render_config: &RenderConfig) -> Result<CiProvider> {
+	println!("{}", style("Generate a CI pipeline:").bold());
+
+	let options = [
+		CiProvider::None,
+		CiProvider::GitHub,
+		CiProvider::GitLab,
+		CiProvider::Forgejo,
+	];
+	Select::new("", options.to_vec())
+		.with_render_config(*render_config)
+		.prompt()
+		.map_err(Into::into)
+}
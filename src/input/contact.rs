@@ -0,0 +1,70 @@
+use anyhow::Result;
+use crossterm::style::Stylize;
+use inquire::{ui::RenderConfig, validator::Validation, Text};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+/// The `contact` links of `fabric.mod.json`. Empty ones are left out.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Contact {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub homepage: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub sources: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub issues: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub discord: Option<String>,
+}
+
+impl Contact {
+	pub fn is_empty(&self) -> bool {
+		self.links().next().is_none()
+	}
+
+	/// The links that were provided, keyed as in `fabric.mod.json`.
+	pub fn links(&self) -> impl Iterator<Item = (&'static str, &str)> {
+		[
+			("homepage", &self.homepage),
+			("sources", &self.sources),
+			("issues", &self.issues),
+			("discord", &self.discord),
+		]
+		.into_iter()
+		.filter_map(|(key, link)| link.as_deref().map(|link| (key, link)))
+	}
+}
+
+/// Asks for each link, starting from the `current` ones.
+pub fn prompt(render_config: &RenderConfig, current: &Contact) -> Result<Contact> {
+	println!("{}", "Contact links (leave empty to skip):".bold());
+
+	let link = |label: &str, current: &Option<String>| -> Result<Option<String>> {
+		let message = label.bold().to_string();
+		let mut prompt = Text::new(&message)
+			.with_validator(validate_url)
+			.with_render_config(*render_config);
+		prompt.initial_value = current.as_deref();
+		let url = prompt.prompt()?;
+
+		Ok(Some(url.trim().to_string()).filter(|url| !url.is_empty()))
+	};
+
+	Ok(Contact {
+		homepage: link("Homepage:", &current.homepage)?,
+		sources: link("Sources:", &current.sources)?,
+		issues: link("Issue tracker:", &current.issues)?,
+		discord: link("Discord invite:", &current.discord)?,
+	})
+}
+
+fn validate_url(url: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
+	let url = url.trim();
+	if url.is_empty() || url.starts_with("https://") || url.starts_with("http://") {
+		Ok(Validation::Valid)
+	} else {
+		Ok(Validation::Invalid(
+			"Links must start with http:// or https://".into(),
+		))
+	}
+}
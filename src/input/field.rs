@@ -0,0 +1,232 @@
+use super::UserInput;
+use crate::features::datagen;
+use crate::project::mod_json::VersionRange;
+use crossterm::style::{StyledContent, Stylize};
+
+/// A question of the setup flow, in the order they are asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+	Location,
+	MavenGroup,
+	Package,
+	GradleDsl,
+	VersionCatalog,
+	GradleSettings,
+	MinecraftVersion,
+	MinecraftRange,
+	Yarn,
+	Loader,
+	FabricApi,
+	Version,
+	Description,
+	Authors,
+	Contributors,
+	Contact,
+	License,
+	Icon,
+	Locales,
+	Mixin,
+	Datagen,
+	AccessWidener,
+	Junit,
+	Gametest,
+	Publishing,
+	Git,
+	Ci,
+	Ides,
+	Decompiler,
+}
+
+impl Field {
+	pub const ALL: [Field; 29] = [
+		Field::Location,
+		Field::MavenGroup,
+		Field::Package,
+		Field::GradleDsl,
+		Field::VersionCatalog,
+		Field::GradleSettings,
+		Field::MinecraftVersion,
+		Field::MinecraftRange,
+		Field::Yarn,
+		Field::Loader,
+		Field::FabricApi,
+		Field::Version,
+		Field::Description,
+		Field::Authors,
+		Field::Contributors,
+		Field::Contact,
+		Field::License,
+		Field::Icon,
+		Field::Locales,
+		Field::Mixin,
+		Field::Datagen,
+		Field::AccessWidener,
+		Field::Junit,
+		Field::Gametest,
+		Field::Publishing,
+		Field::Git,
+		Field::Ci,
+		Field::Ides,
+		Field::Decompiler,
+	];
+
+	pub const fn label(self) -> &'static str {
+		match self {
+			Field::Location => "Mod name",
+			Field::MavenGroup => "Maven group",
+			Field::Package => "Package",
+			Field::GradleDsl => "Gradle DSL",
+			Field::VersionCatalog => "Version catalog",
+			Field::GradleSettings => "Gradle settings",
+			Field::MinecraftVersion => "Minecraft version",
+			Field::MinecraftRange => "Supported Minecraft versions",
+			Field::Yarn => "Yarn mappings",
+			Field::Loader => "Fabric Loader",
+			Field::FabricApi => "Fabric API",
+			Field::Version => "Mod version",
+			Field::Description => "Description",
+			Field::Authors => "Authors",
+			Field::Contributors => "Contributors",
+			Field::Contact => "Contact links",
+			Field::License => "License",
+			Field::Icon => "Icon",
+			Field::Locales => "Extra translations",
+			Field::Mixin => "Example mixin",
+			Field::Datagen => "Data generation",
+			Field::AccessWidener => "Access widener",
+			Field::Junit => "JUnit tests",
+			Field::Gametest => "Gametests",
+			Field::Publishing => "Publishing",
+			Field::Git => "Git repository",
+			Field::Ci => "CI pipeline",
+			Field::Ides => "Run configurations",
+			Field::Decompiler => "Decompiler",
+		}
+	}
+
+	/// The heading printed before this field when the questions are first asked.
+	pub fn section(self) -> Option<StyledContent<&'static str>> {
+		match self {
+			Field::MinecraftVersion => Some(
+				"📦 Now, let's choose your mod's dependencies:"
+					.green()
+					.bold(),
+			),
+			Field::Version => Some("📝 Let's add some details about your mod:".yellow().bold()),
+			Field::Mixin => Some("🧩 Pick any extras you'd like set up:".blue().bold()),
+			_ => None,
+		}
+	}
+
+	/// Fields whose answers only make sense for a particular answer to this one,
+	/// and that have to be asked again when it changes.
+	pub const fn dependents(self) -> &'static [Field] {
+		match self {
+			Field::MinecraftVersion => &[
+				Field::MinecraftRange,
+				Field::Yarn,
+				Field::FabricApi,
+				Field::Datagen,
+			],
+			Field::Location | Field::MavenGroup => &[Field::Package],
+			Field::Git => &[Field::Ci],
+			Field::Ides => &[Field::Decompiler],
+			_ => &[],
+		}
+	}
+
+	/// Whether the question is asked at all, given the other answers.
+	pub fn applies_to(self, input: &UserInput) -> bool {
+		match self {
+			Field::Ci => input.git,
+			Field::Datagen => datagen::is_supported(&input.minecraft_version),
+			Field::Decompiler => !input.ides.is_empty(),
+			_ => true,
+		}
+	}
+
+	/// The answer as shown in the summary.
+	pub fn value(self, input: &UserInput) -> String {
+		match self {
+			Field::Location => input.location.mod_name.clone(),
+			Field::MavenGroup => input.maven_group.clone(),
+			Field::Package => input.package.clone(),
+			Field::GradleDsl => input.gradle_dsl.to_string(),
+			Field::VersionCatalog => yes_no(input.version_catalog),
+			Field::GradleSettings => input.gradle_settings.summary(),
+			Field::MinecraftVersion => input.minecraft_version.clone(),
+			Field::MinecraftRange => {
+				display_range(&input.minecraft_range.range(&input.minecraft_version))
+			}
+			Field::Yarn => input.yarn_version.clone(),
+			Field::Loader => input.fabric_loader_version.clone(),
+			Field::FabricApi => input.fabric_api_version.clone(),
+			Field::Version => input.version.clone(),
+			Field::Description => input.description.clone(),
+			Field::Authors => input.authors.join(", "),
+			Field::Contributors => or_none(input.contributors.join(", ")),
+			Field::Contact => or_none(
+				input
+					.contact
+					.links()
+					.map(|(key, link)| format!("{}: {}", key, link))
+					.collect::<Vec<_>>()
+					.join(", "),
+			),
+			Field::License => input.license.clone(),
+			Field::Icon => input
+				.icon
+				.as_ref()
+				.map_or("generated".to_string(), |path| path.display().to_string()),
+			Field::Locales => or_none(input.locales.join(", ")),
+			Field::Mixin => yes_no(input.mixin),
+			Field::Datagen => yes_no(input.datagen),
+			Field::AccessWidener => yes_no(input.access_widener),
+			Field::Junit => yes_no(input.junit),
+			Field::Gametest => yes_no(input.gametest),
+			Field::Publishing => {
+				let platforms = [
+					("Modrinth", &input.publishing.modrinth),
+					("CurseForge", &input.publishing.curseforge),
+				];
+				or_none(
+					platforms
+						.into_iter()
+						.filter_map(|(name, id)| id.as_ref().map(|id| format!("{} ({})", name, id)))
+						.collect::<Vec<_>>()
+						.join(", "),
+				)
+			}
+			Field::Git => yes_no(input.git),
+			Field::Ci => input.ci.to_string(),
+			Field::Ides => or_none(
+				input
+					.ides
+					.iter()
+					.map(ToString::to_string)
+					.collect::<Vec<_>>()
+					.join(", "),
+			),
+			Field::Decompiler => input.gradle_settings.decompiler.to_string(),
+		}
+	}
+}
+
+pub fn display_range(range: &VersionRange) -> String {
+	match range {
+		VersionRange::One(range) => range.clone(),
+		VersionRange::Any(ranges) => ranges.join(" or "),
+	}
+}
+
+fn yes_no(value: bool) -> String {
+	if value { "yes" } else { "no" }.to_string()
+}
+
+fn or_none(value: String) -> String {
+	if value.is_empty() {
+		"none".to_string()
+	} else {
+		value
+	}
+}
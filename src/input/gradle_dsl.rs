@@ -1,10 +1,13 @@
 use anyhow::Result;
 use crossterm::style::{style, Stylize};
 use inquire::{ui::RenderConfig, Select};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
 pub enum GradleDsl {
+	#[default]
 	Groovy,
 	Kotlin,
 }
@@ -16,6 +19,13 @@ impl GradleDsl {
 			GradleDsl::Kotlin => "kotlin",
 		}
 	}
+
+	pub const fn build_file_name(self) -> &'static str {
+		match self {
+			GradleDsl::Groovy => "build.gradle",
+			GradleDsl::Kotlin => "build.gradle.kts",
+		}
+	}
 }
 
 impl fmt::Display for GradleDsl {
@@ -24,11 +34,15 @@ impl fmt::Display for GradleDsl {
 	}
 }
 
-pub fn prompt(render_config: &RenderConfig) -> Result<GradleDsl> {
+pub fn prompt(render_config: &RenderConfig, default: Option<GradleDsl>) -> Result<GradleDsl> {
 	println!("{}", style("Select Gradle DSL:").bold());
 
 	let options = [GradleDsl::Groovy, GradleDsl::Kotlin];
+	let starting_cursor = default
+		.and_then(|dsl| options.iter().position(|&option| option == dsl))
+		.unwrap_or(0);
 	Select::new("", options.to_vec())
+		.with_starting_cursor(starting_cursor)
 		.with_render_config(*render_config)
 		.prompt()
 		.map_err(Into::into)
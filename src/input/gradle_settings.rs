@@ -0,0 +1,189 @@
+use anyhow::Result;
+use crossterm::style::Stylize;
+use inquire::{ui::RenderConfig, validator::Validation, Confirm, Select, Text};
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt};
+
+/// Build settings written to the top of `gradle.properties`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct GradleSettings {
+	/// Maximum heap of the Gradle daemon, e.g. `4G` or `2048M`.
+	pub heap_size: String,
+	pub parallel: bool,
+	pub configuration_cache: bool,
+	pub build_cache: bool,
+	pub daemon: bool,
+	pub decompiler: Decompiler,
+}
+
+impl Default for GradleSettings {
+	fn default() -> Self {
+		Self {
+			heap_size: "4G".to_string(),
+			parallel: true,
+			configuration_cache: false,
+			build_cache: false,
+			daemon: true,
+			decompiler: Decompiler::default(),
+		}
+	}
+}
+
+impl GradleSettings {
+	/// The `org.gradle.*` lines for `gradle.properties`.
+	pub fn properties(&self) -> String {
+		[
+			format!("org.gradle.jvmargs=-Xmx{}", self.heap_size),
+			format!("org.gradle.parallel={}", self.parallel),
+			format!(
+				"org.gradle.configuration-cache={}",
+				self.configuration_cache
+			),
+			format!("org.gradle.caching={}", self.build_cache),
+			format!("org.gradle.daemon={}", self.daemon),
+		]
+		.join("\n")
+	}
+
+	/// A one-line description, e.g. `4G heap, parallel, daemon`.
+	pub fn summary(&self) -> String {
+		let options = [
+			(self.parallel, "parallel"),
+			(self.configuration_cache, "configuration cache"),
+			(self.build_cache, "build cache"),
+			(self.daemon, "daemon"),
+		];
+
+		std::iter::once(format!("{} heap", self.heap_size))
+			.chain(
+				options
+					.into_iter()
+					.filter(|(enabled, _)| *enabled)
+					.map(|(_, name)| name.to_string()),
+			)
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+}
+
+/// The decompiler Loom uses to generate Minecraft sources.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Decompiler {
+	#[default]
+	Vineflower,
+	Cfr,
+	Fernflower,
+}
+
+impl Decompiler {
+	pub const ALL: [Self; 3] = [Self::Vineflower, Self::Cfr, Self::Fernflower];
+
+	/// The Loom task that generates sources with this decompiler.
+	pub fn gen_sources_task(self) -> &'static str {
+		match self {
+			Self::Vineflower => "genSourcesWithVineflower",
+			Self::Cfr => "genSourcesWithCfr",
+			Self::Fernflower => "genSourcesWithFernFlower",
+		}
+	}
+}
+
+impl fmt::Display for Decompiler {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Vineflower => "Vineflower",
+			Self::Cfr => "CFR",
+			Self::Fernflower => "Fernflower",
+		})
+	}
+}
+
+/// Asks whether to change the saved Gradle settings, and for each setting if so.
+pub fn prompt(render_config: &RenderConfig, defaults: &GradleSettings) -> Result<GradleSettings> {
+	let customize = Confirm::new(&"Customize Gradle settings?".bold().to_string())
+		.with_help_message(&format!("Currently {}", defaults.summary()))
+		.with_default(false)
+		.with_render_config(*render_config)
+		.prompt()?;
+	if !customize {
+		return Ok(defaults.clone());
+	}
+
+	let heap_size = Text::new(&"Gradle heap size:".bold().to_string())
+		.with_default(&defaults.heap_size)
+		.with_help_message("e.g. 2G on machines with 8 GB of memory or less")
+		.with_validator(|size: &str| validate_heap_size(size.trim()))
+		.with_render_config(*render_config)
+		.prompt()?
+		.trim()
+		.to_uppercase();
+
+	let confirm = |message: &str, help: &str, default: bool| {
+		Confirm::new(&message.bold().to_string())
+			.with_help_message(help)
+			.with_default(default)
+			.with_render_config(*render_config)
+			.prompt()
+	};
+	let parallel = confirm(
+		"Build projects in parallel?",
+		"org.gradle.parallel",
+		defaults.parallel,
+	)?;
+	let configuration_cache = confirm(
+		"Enable the configuration cache?",
+		"Speeds up repeated builds, e.g. on CI",
+		defaults.configuration_cache,
+	)?;
+	let build_cache = confirm(
+		"Enable the build cache?",
+		"Reuses task outputs from earlier builds",
+		defaults.build_cache,
+	)?;
+	let daemon = confirm(
+		"Keep a Gradle daemon running between builds?",
+		"Turn off for CI and machines short on memory",
+		defaults.daemon,
+	)?;
+
+	Ok(GradleSettings {
+		heap_size,
+		parallel,
+		configuration_cache,
+		build_cache,
+		daemon,
+		// Only run configurations use it, so it's asked for along with those.
+		decompiler: defaults.decompiler,
+	})
+}
+
+pub fn prompt_decompiler(render_config: &RenderConfig, current: Decompiler) -> Result<Decompiler> {
+	Select::new(
+		&"Decompiler for Minecraft sources:".bold().to_string(),
+		Decompiler::ALL.to_vec(),
+	)
+	.with_help_message("Used by the Generate Sources run configuration")
+	.with_starting_cursor(
+		Decompiler::ALL
+			.iter()
+			.position(|&d| d == current)
+			.unwrap_or(0),
+	)
+	.with_render_config(*render_config)
+	.prompt()
+	.map_err(Into::into)
+}
+
+pub fn validate_heap_size(size: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
+	let valid = size
+		.strip_suffix(['M', 'm', 'G', 'g'])
+		.is_some_and(|amount| amount.parse::<u32>().is_ok_and(|amount| amount > 0));
+
+	Ok(if valid {
+		Validation::Valid
+	} else {
+		Validation::Invalid("Use a number followed by M or G, like 2G or 1536M".into())
+	})
+}
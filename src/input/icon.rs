@@ -0,0 +1,66 @@
+use anyhow::{bail, Context, Result};
+use crossterm::style::Stylize;
+use inquire::{ui::RenderConfig, validator::Validation, Confirm, Text};
+use std::{error::Error, fs::File, io::BufReader, path::Path, path::PathBuf};
+
+/// Icon sizes Mod Menu and launchers display without visible scaling artifacts.
+const MIN_SIZE: u32 = 16;
+const MAX_SIZE: u32 = 1024;
+
+/// Asks for the user's own icon, starting from the `current` one. `None` means
+/// a placeholder gets generated.
+pub fn prompt(render_config: &RenderConfig, current: Option<&Path>) -> Result<Option<PathBuf>> {
+	let custom = Confirm::new(&"Use your own icon?".bold().to_string())
+		.with_help_message("Otherwise a placeholder is generated from the mod ID")
+		.with_default(current.is_some())
+		.with_render_config(*render_config)
+		.prompt()?;
+	if !custom {
+		return Ok(None);
+	}
+
+	let message = "Path to the icon PNG:".bold().to_string();
+	let current = current.map(|path| path.display().to_string());
+	let mut path_prompt = Text::new(&message)
+		.with_validator(validate_path)
+		.with_render_config(*render_config);
+	path_prompt.initial_value = current.as_deref();
+	let path = path_prompt.prompt()?;
+
+	let path = dunce::canonicalize(path.trim()).context("Failed to resolve the icon path")?;
+	Ok(Some(path))
+}
+
+/// Checks that `path` is a square PNG with a power-of-two size, returning that size.
+pub fn validate_icon(path: &Path) -> Result<u32> {
+	let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+	let reader = png::Decoder::new(BufReader::new(file))
+		.read_info()
+		.with_context(|| format!("{} is not a valid PNG", path.display()))?;
+	let info = reader.info();
+
+	if info.width != info.height {
+		bail!(
+			"Icons must be square, but this one is {}x{}",
+			info.width,
+			info.height
+		);
+	}
+	if !info.width.is_power_of_two() || !(MIN_SIZE..=MAX_SIZE).contains(&info.width) {
+		bail!(
+			"Icons must be a power of two between {0}x{0} and {1}x{1}, but this one is {2}x{2}",
+			MIN_SIZE,
+			MAX_SIZE,
+			info.width
+		);
+	}
+
+	Ok(info.width)
+}
+
+fn validate_path(path: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
+	Ok(match validate_icon(Path::new(path.trim())) {
+		Ok(_) => Validation::Valid,
+		Err(e) => Validation::Invalid(format!("{:#}", e).into()),
+	})
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use crossterm::style::{style, Stylize};
+use inquire::{ui::RenderConfig, MultiSelect};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Ide {
+	IntelliJ,
+	VsCode,
+}
+
+impl Ide {
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Ide::IntelliJ => "IntelliJ IDEA",
+			Ide::VsCode => "Visual Studio Code",
+		}
+	}
+}
+
+impl fmt::Display for Ide {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// Asks which IDEs to set up, with the `current` ones selected.
+pub fn prompt(render_config: &RenderConfig, current: &[Ide]) -> Result<Vec<Ide>> {
+	println!("{}", style("Generate run configurations for:").bold());
+
+	let ides = vec![Ide::IntelliJ, Ide::VsCode];
+	let selected: Vec<usize> = ides
+		.iter()
+		.enumerate()
+		.filter(|(_, ide)| current.contains(ide))
+		.map(|(index, _)| index)
+		.collect();
+
+	MultiSelect::new("", ides)
+		.with_default(&selected)
+		.with_render_config(*render_config)
+		.prompt()
+		.map_err(Into::into)
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use crossterm::style::Stylize;
+use inquire::{ui::RenderConfig, validator::Validation, Select, Text};
+use std::{error::Error, fmt};
+
+/// The identifier Modrinth and others use for mods that aren't open source.
+pub const ALL_RIGHTS_RESERVED: &str = "LicenseRef-All-Rights-Reserved";
+
+/// Listed first; everything else follows in SPDX order.
+const POPULAR_LICENSES: &[&str] = &[
+	"MIT",
+	"Apache-2.0",
+	"LGPL-3.0-only",
+	"GPL-3.0-only",
+	"MPL-2.0",
+	"CC0-1.0",
+];
+
+#[derive(Debug, Clone, Copy)]
+enum LicenseChoice {
+	Spdx(spdx::LicenseId),
+	AllRightsReserved,
+	Custom,
+}
+
+impl fmt::Display for LicenseChoice {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			LicenseChoice::Spdx(id) => write!(f, "{} ({})", id.name, id.full_name),
+			LicenseChoice::AllRightsReserved => {
+				write!(f, "All Rights Reserved ({})", ALL_RIGHTS_RESERVED)
+			}
+			LicenseChoice::Custom => f.write_str("Custom SPDX expression..."),
+		}
+	}
+}
+
+/// Asks for the license. A `default` that isn't in the list is offered as the
+/// pre-filled custom expression.
+pub fn prompt(render_config: &RenderConfig, default: Option<&str>) -> Result<String> {
+	let popular = POPULAR_LICENSES
+		.iter()
+		.filter_map(|name| spdx::license_id(name))
+		.map(LicenseChoice::Spdx);
+	let others = spdx::identifiers::LICENSES
+		.iter()
+		.filter(|(name, _, _)| !POPULAR_LICENSES.contains(name))
+		.filter_map(|(name, _, _)| spdx::license_id(name))
+		.filter(|id| !id.is_deprecated())
+		.map(LicenseChoice::Spdx);
+
+	let choices: Vec<_> = popular
+		.chain([LicenseChoice::AllRightsReserved, LicenseChoice::Custom])
+		.chain(others)
+		.collect();
+
+	let starting_cursor = default
+		.and_then(|default| {
+			choices
+				.iter()
+				.position(|choice| match choice {
+					LicenseChoice::Spdx(id) => id.name == default,
+					LicenseChoice::AllRightsReserved => default == ALL_RIGHTS_RESERVED,
+					LicenseChoice::Custom => false,
+				})
+				.or_else(|| {
+					choices
+						.iter()
+						.position(|choice| matches!(choice, LicenseChoice::Custom))
+				})
+		})
+		.unwrap_or(0);
+
+	let choice = Select::new(&"Mod license (type to search):".bold().to_string(), choices)
+		.with_page_size(10)
+		.with_starting_cursor(starting_cursor)
+		.with_render_config(*render_config)
+		.prompt()?;
+
+	Ok(match choice {
+		LicenseChoice::Spdx(id) => id.name.to_string(),
+		LicenseChoice::AllRightsReserved => ALL_RIGHTS_RESERVED.to_string(),
+		LicenseChoice::Custom => {
+			let message = "SPDX license expression:".bold().to_string();
+			let mut prompt = Text::new(&message)
+				.with_validator(validate_license)
+				.with_render_config(*render_config);
+			prompt.initial_value = default;
+			prompt.prompt()?
+		}
+	})
+}
+
+pub fn validate_license(license: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
+	match spdx::Expression::parse(license) {
+		Ok(_) => Ok(Validation::Valid),
+		Err(e) => Ok(Validation::Invalid(
+			format!("'{}' is not a valid SPDX expression: {}", license, e.reason).into(),
+		)),
+	}
+}
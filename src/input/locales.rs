@@ -0,0 +1,103 @@
+use anyhow::Result;
+use crossterm::style::Stylize;
+use inquire::{ui::RenderConfig, MultiSelect};
+use std::fmt;
+
+/// Minecraft's language codes, as used for `assets/<modid>/lang/<code>.json`.
+/// `en_us` is always generated and therefore not listed.
+const LOCALES: &[(&str, &str)] = &[
+	("ar_sa", "Arabic"),
+	("be_by", "Belarusian"),
+	("bg_bg", "Bulgarian"),
+	("cs_cz", "Czech"),
+	("da_dk", "Danish"),
+	("de_de", "German"),
+	("el_gr", "Greek"),
+	("en_au", "English (Australia)"),
+	("en_ca", "English (Canada)"),
+	("en_gb", "English (United Kingdom)"),
+	("en_pt", "Pirate Speak"),
+	("es_ar", "Spanish (Argentina)"),
+	("es_es", "Spanish (Spain)"),
+	("es_mx", "Spanish (Mexico)"),
+	("et_ee", "Estonian"),
+	("fa_ir", "Persian"),
+	("fi_fi", "Finnish"),
+	("fil_ph", "Filipino"),
+	("fr_ca", "French (Canada)"),
+	("fr_fr", "French (France)"),
+	("he_il", "Hebrew"),
+	("hi_in", "Hindi"),
+	("hr_hr", "Croatian"),
+	("hu_hu", "Hungarian"),
+	("id_id", "Indonesian"),
+	("it_it", "Italian"),
+	("ja_jp", "Japanese"),
+	("kk_kz", "Kazakh"),
+	("ko_kr", "Korean"),
+	("lol_us", "LOLCAT"),
+	("lt_lt", "Lithuanian"),
+	("lv_lv", "Latvian"),
+	("ms_my", "Malay"),
+	("nb_no", "Norwegian Bokmål"),
+	("nl_nl", "Dutch"),
+	("nn_no", "Norwegian Nynorsk"),
+	("pl_pl", "Polish"),
+	("pt_br", "Portuguese (Brazil)"),
+	("pt_pt", "Portuguese (Portugal)"),
+	("ro_ro", "Romanian"),
+	("ru_ru", "Russian"),
+	("sk_sk", "Slovak"),
+	("sl_si", "Slovenian"),
+	("sr_sp", "Serbian (Cyrillic)"),
+	("sv_se", "Swedish"),
+	("th_th", "Thai"),
+	("tr_tr", "Turkish"),
+	("uk_ua", "Ukrainian"),
+	("vi_vn", "Vietnamese"),
+	("zh_cn", "Chinese (Simplified)"),
+	("zh_hk", "Chinese (Hong Kong)"),
+	("zh_tw", "Chinese (Traditional)"),
+];
+
+#[derive(Debug, Clone, Copy)]
+struct Locale {
+	code: &'static str,
+	name: &'static str,
+}
+
+impl fmt::Display for Locale {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} ({})", self.code, self.name)
+	}
+}
+
+/// Asks for locales to add next to `en_us`, with the `current` ones selected.
+pub fn prompt(render_config: &RenderConfig, current: &[String]) -> Result<Vec<String>> {
+	let locales = LOCALES
+		.iter()
+		.map(|&(code, name)| Locale { code, name })
+		.collect();
+	let selected: Vec<usize> = LOCALES
+		.iter()
+		.enumerate()
+		.filter(|(_, (code, _))| current.iter().any(|current| current == code))
+		.map(|(index, _)| index)
+		.collect();
+
+	let selected = MultiSelect::new(
+		&"Extra translations (type to search, optional):"
+			.bold()
+			.to_string(),
+		locales,
+	)
+	.with_page_size(10)
+	.with_default(&selected)
+	.with_render_config(*render_config)
+	.prompt()?;
+
+	Ok(selected
+		.into_iter()
+		.map(|locale| locale.code.to_string())
+		.collect())
+}
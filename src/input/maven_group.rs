@@ -1,39 +1,230 @@
 use anyhow::Result;
 use crossterm::style::Stylize;
-use inquire::{ui::RenderConfig, validator::Validation, Text};
+use inquire::{ui::RenderConfig, validator::Validation, Confirm, Text};
 use std::error::Error;
 
-pub fn prompt(render_config: &RenderConfig) -> Result<String> {
-	Text::new(
-		&"What is your Maven group (e.g: com.example)?"
-			.bold()
-			.to_string(),
-	)
-	.with_validator(validate_maven_group)
-	.with_render_config(render_config.clone())
-	.prompt()
-	.map_err(Into::into)
+use super::warn_render_config;
+use crate::utils::is_java_identifier;
+
+/// Top-level domains reserved by RFC 2606, which nobody can own.
+const RESERVED_TLDS: &[&str] = &["example", "invalid", "localhost", "test"];
+/// Packages that belong to Minecraft and Fabric themselves.
+const TAKEN_NAMESPACES: &[&str] = &["com.mojang", "net.minecraft", "net.fabricmc"];
+
+pub fn prompt(render_config: &RenderConfig, default: Option<&str>) -> Result<String> {
+	let message = "What is your Maven group (e.g: io.github.yourname)?"
+		.bold()
+		.to_string();
+
+	loop {
+		let mut prompt = Text::new(&message)
+			.with_validator(validate_maven_group)
+			.with_render_config(*render_config);
+		// Saved defaults may predate the current rules.
+		prompt.default =
+			default.filter(|group| matches!(validate_maven_group(group), Ok(Validation::Valid)));
+		let maven_group = prompt.prompt()?;
+
+		let Some(warning) = namespace_warning(&maven_group) else {
+			return Ok(maven_group);
+		};
+		let use_anyway = Confirm::new(&format!("{} Use it anyway?", warning).bold().to_string())
+			.with_default(false)
+			.with_render_config(warn_render_config())
+			.prompt()?;
+		if use_anyway {
+			return Ok(maven_group);
+		}
+	}
+}
+
+/// Asks for the package of the generated classes, suggesting one named after
+/// the mod inside the Maven group. `current` is suggested instead as long as it
+/// is still inside the group.
+pub fn prompt_package(
+	render_config: &RenderConfig,
+	maven_group: &str,
+	mod_id: &str,
+	current: Option<&str>,
+) -> Result<String> {
+	let suggestion = suggest_package(maven_group, mod_id, current);
+
+	let message = "Java package:".bold().to_string();
+	let mut prompt = Text::new(&message)
+		.with_validator(validate_package)
+		.with_render_config(*render_config);
+	if matches!(validate_package(&suggestion), Ok(Validation::Valid)) {
+		prompt.default = Some(&suggestion);
+	} else {
+		prompt.initial_value = Some(&suggestion);
+	}
+
+	prompt.prompt().map_err(Into::into)
+}
+
+/// `<group>.<mod_id>`, with dashes turned into underscores as packages can't
+/// contain them.
+fn suggest_package(maven_group: &str, mod_id: &str, current: Option<&str>) -> String {
+	match current {
+		Some(current)
+			if current == maven_group || current.starts_with(&format!("{}.", maven_group)) =>
+		{
+			current.to_string()
+		}
+		_ => format!("{}.{}", maven_group, mod_id.replace('-', "_")),
+	}
 }
 
 pub fn validate_maven_group(maven_group: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
-	if maven_group.is_empty() {
-		return Ok(Validation::Invalid("Maven group cannot be empty".bold().into()));
+	Ok(check_package_name("Maven group", maven_group))
+}
+
+pub fn validate_package(package: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
+	Ok(check_package_name("Package", package))
+}
+
+/// Checks that every segment of `name` is a lowercase Java identifier, since
+/// both the Maven group and the package end up as Java package names.
+fn check_package_name(kind: &str, name: &str) -> Validation {
+	if name.is_empty() {
+		return Validation::Invalid(format!("{} cannot be empty", kind).into());
 	}
 
-	if !maven_group
-		.chars()
-		.all(|c| c.is_ascii_alphanumeric() || c == '.')
-	{
-		return Ok(Validation::Invalid(
-			"Maven group can only contain alphanumeric characters and dots".into(),
-		));
+	for segment in name.split('.') {
+		let message = if segment.is_empty() {
+			format!("{} cannot start or end with a dot or contain '..'", kind)
+		} else if segment.starts_with(|c: char| c.is_ascii_digit()) {
+			format!("'{}' cannot start with a digit", segment)
+		} else if segment.chars().any(|c| c.is_ascii_uppercase()) {
+			format!("'{}' must be lowercase", segment)
+		} else if !is_java_identifier(segment) {
+			if segment
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+			{
+				format!("'{}' is a reserved Java keyword", segment)
+			} else {
+				format!(
+					"'{}' can only contain lowercase letters, digits and underscores",
+					segment
+				)
+			}
+		} else {
+			continue;
+		};
+
+		return Validation::Invalid(message.into());
 	}
 
-	if maven_group.starts_with('.') || maven_group.ends_with('.') {
-		return Ok(Validation::Invalid(
-			"Maven group cannot start or end with a dot".into(),
+	Validation::Valid
+}
+
+/// A warning for groups under a domain nobody can own, or one that belongs to
+/// Minecraft or Fabric.
+fn namespace_warning(maven_group: &str) -> Option<String> {
+	let mut segments = maven_group.split('.');
+	let tld = segments.next()?;
+	let domain = segments.next();
+
+	if RESERVED_TLDS.contains(&tld) {
+		return Some(format!("'.{}' is a reserved top-level domain.", tld));
+	}
+	if domain == Some("example") {
+		return Some(format!(
+			"example.{} is reserved for documentation; consider io.github.<username>.",
+			tld
 		));
 	}
 
-	Ok(Validation::Valid)
+	TAKEN_NAMESPACES
+		.iter()
+		.find(|namespace| {
+			maven_group == **namespace || maven_group.starts_with(&format!("{}.", namespace))
+		})
+		.map(|namespace| format!("{} belongs to Minecraft or Fabric.", namespace))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rejects_illegal_package_names() {
+		for name in [
+			"",
+			"com..example",
+			".com.example",
+			"com.example.",
+			"com.1example",
+			"com.Example",
+			"com.example.class",
+			"com.example._",
+			"com.my-mod",
+			"com.my mod",
+		] {
+			assert!(
+				matches!(check_package_name("Package", name), Validation::Invalid(_)),
+				"{}",
+				name
+			);
+		}
+	}
+
+	#[test]
+	fn accepts_legal_package_names() {
+		for name in [
+			"io.github.someone",
+			"com.example2",
+			"dev.my_mod",
+			"net.x.y_z",
+		] {
+			assert!(
+				matches!(check_package_name("Package", name), Validation::Valid),
+				"{}",
+				name
+			);
+		}
+	}
+
+	#[test]
+	fn warns_about_namespaces_nobody_owns() {
+		for group in [
+			"test.mymod",
+			"example.mymod",
+			"com.example",
+			"org.example.mymod",
+			"net.minecraft",
+			"net.fabricmc.mymod",
+		] {
+			assert!(namespace_warning(group).is_some(), "{}", group);
+		}
+
+		for group in ["io.github.someone", "com.mojangfan", "net.fabricmcx.mod"] {
+			assert!(namespace_warning(group).is_none(), "{}", group);
+		}
+	}
+
+	#[test]
+	fn package_is_derived_from_group_and_mod_id() {
+		assert_eq!(
+			suggest_package("io.github.someone", "better-combat", None),
+			"io.github.someone.better_combat"
+		);
+		assert_eq!(
+			suggest_package(
+				"io.github.someone",
+				"bettercombat",
+				Some("io.github.someone.combat")
+			),
+			"io.github.someone.combat"
+		);
+		assert_eq!(
+			suggest_package(
+				"io.github.other",
+				"bettercombat",
+				Some("io.github.someone.combat")
+			),
+			"io.github.other.bettercombat"
+		);
+	}
 }
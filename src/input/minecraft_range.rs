@@ -0,0 +1,235 @@
+use crate::project::mod_json::VersionRange;
+use crate::utils::{loader_minecraft_version, parse_version};
+use anyhow::Result;
+use crossterm::style::{style, Stylize};
+use inquire::{ui::RenderConfig, validator::Validation, Select, Text};
+use semver::VersionReq;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+const OPERATORS: [&str; 7] = [">=", "<=", ">", "<", "=", "~", "^"];
+
+/// Which Minecraft versions the mod declares it works with in `fabric.mod.json`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MinecraftRange {
+	/// The chosen version and later patch releases, e.g. `~1.21`.
+	#[default]
+	Patch,
+	/// Only the chosen version.
+	Exact,
+	/// Every release of the chosen minor version, e.g. `1.21.x`.
+	Wildcard,
+	/// Comma-separated alternatives, each a space-separated list of comparators.
+	Custom(String),
+}
+
+impl MinecraftRange {
+	pub fn range(&self, minecraft_version: &str) -> VersionRange {
+		let minecraft_version = loader_minecraft_version(minecraft_version);
+		match self {
+			MinecraftRange::Patch => format!("~{}", minecraft_version).into(),
+			MinecraftRange::Exact => minecraft_version.into(),
+			MinecraftRange::Wildcard => {
+				let minor = minecraft_version
+					.split('.')
+					.take(2)
+					.collect::<Vec<_>>()
+					.join(".");
+				format!("{}.x", minor).into()
+			}
+			MinecraftRange::Custom(ranges) => {
+				let mut alternatives: Vec<String> = ranges
+					.split(',')
+					.map(|range| range.split_whitespace().collect::<Vec<_>>().join(" "))
+					.filter(|range| !range.is_empty())
+					.collect();
+
+				if alternatives.len() == 1 {
+					alternatives.remove(0).into()
+				} else {
+					VersionRange::Any(alternatives)
+				}
+			}
+		}
+	}
+
+	/// The releases in `known` that the range covers, oldest first, for the
+	/// platforms releases get published to. Falls back to the chosen version
+	/// when the range matches none of them.
+	pub fn releases(&self, minecraft_version: &str, known: &[(String, bool)]) -> Vec<String> {
+		let alternatives: Vec<VersionReq> = match self.range(minecraft_version) {
+			VersionRange::One(range) => vec![range],
+			VersionRange::Any(ranges) => ranges,
+		}
+		.iter()
+		.filter_map(|range| version_req(range))
+		.collect();
+
+		let mut releases: Vec<String> = known
+			.iter()
+			.filter(|(_, stable)| *stable)
+			.filter(|(version, _)| {
+				parse_version(version)
+					.is_some_and(|version| alternatives.iter().any(|req| req.matches(&version)))
+			})
+			.map(|(version, _)| version.clone())
+			.collect();
+		releases.sort_by_key(|version| parse_version(version));
+
+		if releases.is_empty() {
+			releases.push(minecraft_version.to_string());
+		}
+		releases
+	}
+}
+
+/// Translates a `fabric.mod.json` version range into a semver requirement.
+/// A version without an operator only matches itself there, and Minecraft
+/// versions like `1.21` are short for `1.21.0`.
+fn version_req(range: &str) -> Option<VersionReq> {
+	let comparators: Vec<String> = range
+		.split_whitespace()
+		.map(|comparator| {
+			let version = OPERATORS
+				.iter()
+				.find_map(|operator| comparator.strip_prefix(operator))
+				.unwrap_or(comparator);
+			let operator = &comparator[..comparator.len() - version.len()];
+
+			if version.contains(['x', 'X', '*']) {
+				return comparator.to_string();
+			}
+
+			let version = parse_version(version).map_or(version.to_string(), |v| v.to_string());
+			let operator = if operator.is_empty() { "=" } else { operator };
+			format!("{}{}", operator, version)
+		})
+		.collect();
+
+	VersionReq::parse(&comparators.join(", ")).ok()
+}
+
+pub fn prompt(render_config: &RenderConfig, minecraft_version: &str) -> Result<MinecraftRange> {
+	println!("{}", style("Supported Minecraft versions:").bold());
+
+	let options = [
+		MinecraftRange::Patch,
+		MinecraftRange::Exact,
+		MinecraftRange::Wildcard,
+	];
+	let mut labels: Vec<String> = options
+		.iter()
+		.map(|option| {
+			let VersionRange::One(range) = option.range(minecraft_version) else {
+				unreachable!("only custom ranges have alternatives");
+			};
+			range
+		})
+		.collect();
+	labels.push("Custom range".to_string());
+
+	let selected = Select::new("", labels)
+		.with_render_config(*render_config)
+		.raw_prompt()?;
+
+	if let Some(option) = options.get(selected.index) {
+		return Ok(option.clone());
+	}
+
+	let ranges = Text::new(&"Minecraft version range:".bold().to_string())
+		.with_help_message("e.g. `>=1.20.5 <1.21.2` or `1.20.1, 1.20.4`")
+		.with_validator(validate_ranges)
+		.with_render_config(*render_config)
+		.prompt()?;
+
+	Ok(MinecraftRange::Custom(ranges.trim().to_string()))
+}
+
+fn validate_ranges(ranges: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
+	let alternatives: Vec<&str> = ranges.split(',').map(str::trim).collect();
+	if alternatives.iter().any(|range| range.is_empty()) {
+		return Ok(Validation::Invalid("Ranges cannot be empty".into()));
+	}
+
+	for comparator in alternatives
+		.iter()
+		.flat_map(|range| range.split_whitespace())
+	{
+		let version = OPERATORS
+			.iter()
+			.find_map(|operator| comparator.strip_prefix(operator))
+			.unwrap_or(comparator);
+
+		let is_valid = !version.is_empty()
+			&& version
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '*'));
+		if !is_valid {
+			return Ok(Validation::Invalid(
+				format!("'{}' is not a valid version comparator", comparator).into(),
+			));
+		}
+	}
+
+	Ok(Validation::Valid)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn known() -> Vec<(String, bool)> {
+		[
+			("1.21.3", true),
+			("1.21.2-rc1", false),
+			("1.21.1", true),
+			("1.21", true),
+			("1.20.6", true),
+			("1.20.5", true),
+		]
+		.into_iter()
+		.map(|(version, stable)| (version.to_string(), stable))
+		.collect()
+	}
+
+	#[test]
+	fn releases_follow_the_range() {
+		assert_eq!(MinecraftRange::Exact.releases("1.21", &known()), ["1.21"]);
+		assert_eq!(
+			MinecraftRange::Patch.releases("1.21", &known()),
+			["1.21", "1.21.1", "1.21.3"]
+		);
+		assert_eq!(
+			MinecraftRange::Wildcard.releases("1.20.5", &known()),
+			["1.20.5", "1.20.6"]
+		);
+		assert_eq!(
+			MinecraftRange::Custom(">=1.20.6 <1.21.2, 1.21.3".to_string())
+				.releases("1.21", &known()),
+			["1.20.6", "1.21", "1.21.1", "1.21.3"]
+		);
+	}
+
+	#[test]
+	fn snapshot_ranges_use_fabric_loader_versions() {
+		assert_eq!(
+			MinecraftRange::Patch.range("24w14a"),
+			VersionRange::One("~1.20.5-alpha.24.14.a".to_string())
+		);
+		assert_eq!(
+			MinecraftRange::Exact.range("1.21-pre1"),
+			VersionRange::One("1.21-beta.1".to_string())
+		);
+		assert_eq!(
+			MinecraftRange::Exact.releases("24w14a", &known()),
+			["24w14a"]
+		);
+	}
+
+	#[test]
+	fn releases_fall_back_to_the_chosen_version() {
+		assert_eq!(MinecraftRange::Patch.releases("1.22", &known()), ["1.22"]);
+		assert_eq!(MinecraftRange::Exact.releases("1.21", &[]), ["1.21"]);
+	}
+}
@@ -1,150 +1,600 @@
+pub mod ci_provider;
+pub mod contact;
+mod field;
 pub mod gradle_dsl;
+pub mod gradle_settings;
+pub mod icon;
+pub mod ide;
+pub mod license;
+pub mod locales;
 pub mod maven_group;
+pub mod minecraft_range;
 pub mod project_location;
+pub mod publishing;
+mod summary;
+mod version_select;
 
+use self::ci_provider::CiProvider;
+use self::contact::Contact;
+use self::field::Field;
 use self::gradle_dsl::GradleDsl;
+use self::gradle_settings::GradleSettings;
+use self::ide::Ide;
+use self::minecraft_range::MinecraftRange;
 use self::project_location::ProjectLocation;
-use crate::versions::VersionManager;
+use self::publishing::Publishing;
+use crate::config::{Config, LoaderChannel};
+use crate::project::mod_json::Dependencies;
+use crate::utils::get_java_version;
+use crate::versions::{VersionData, VersionManager};
 
 use anyhow::Result;
 use crossterm::style::Stylize;
 use inquire::{
 	ui::{Color, RenderConfig, Styled},
-	Confirm, Select, Text,
+	validator::Validation,
+	Confirm, InquireError, Select, Text,
 };
+use serde::{Deserialize, Deserializer, Serialize};
+use std::path::PathBuf;
 
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct UserInput {
 	pub location: ProjectLocation,
 	pub maven_group: String,
+	/// The Java package of the generated classes, usually inside the Maven group.
+	#[serde(default)]
+	pub package: String,
 	pub gradle_dsl: GradleDsl,
+	/// Whether dependency versions go into `gradle/libs.versions.toml` instead of `gradle.properties`.
+	#[serde(default)]
+	pub version_catalog: bool,
+	#[serde(default)]
+	pub gradle_settings: GradleSettings,
+	// The dependency versions are kept in the manifest's `[versions]` table,
+	// which `update` and `port` keep current.
+	#[serde(skip)]
 	pub minecraft_version: String,
+	#[serde(default)]
+	pub minecraft_range: MinecraftRange,
+	#[serde(skip)]
 	pub yarn_version: String,
+	#[serde(skip)]
 	pub fabric_loader_version: String,
+	#[serde(skip)]
 	pub fabric_api_version: String,
 	pub version: String,
 	pub description: String,
-	pub author: String,
+	#[serde(alias = "author", deserialize_with = "one_or_many")]
+	pub authors: Vec<String>,
+	#[serde(default)]
+	pub contributors: Vec<String>,
+	#[serde(default)]
+	pub contact: Contact,
 	pub license: String,
+	/// A PNG to use as the mod icon instead of a generated placeholder.
+	#[serde(default)]
+	pub icon: Option<PathBuf>,
+	/// Locales that get a lang file in addition to `en_us`.
+	#[serde(default)]
+	pub locales: Vec<String>,
+	/// A mixin config with an example mixin.
+	#[serde(default)]
+	pub mixin: bool,
+	#[serde(default)]
+	pub datagen: bool,
+	#[serde(default)]
+	pub access_widener: bool,
+	#[serde(default)]
+	pub junit: bool,
+	#[serde(default)]
+	pub gametest: bool,
+	#[serde(default)]
+	pub publishing: Publishing,
 	pub git: bool,
+	#[serde(default)]
+	pub ci: CiProvider,
+	#[serde(default)]
+	pub ides: Vec<Ide>,
 }
 
-pub fn prompt() -> Result<UserInput> {
-	let render_config = RenderConfig::default()
-		.with_prompt_prefix(Styled::new("›").with_fg(Color::DarkMagenta))
-		.with_answered_prompt_prefix(Styled::new("•").with_fg(Color::LightMagenta))
-		.with_scroll_up_prefix(Styled::new("↑").with_fg(Color::DarkMagenta))
-		.with_scroll_down_prefix(Styled::new("↓").with_fg(Color::DarkMagenta));
+impl UserInput {
+	pub fn mod_id(&self) -> &str {
+		&self.location.mod_id
+	}
+
+	/// The name of the main class, which is also used for the entrypoint.
+	pub fn class_name(&self) -> &str {
+		&self.location.class_name
+	}
+
+	/// The Java package the generated classes go into.
+	pub fn package(&self) -> &str {
+		&self.package
+	}
+
+	/// The `depends` section of `fabric.mod.json`, derived from the chosen versions.
+	pub fn dependencies(&self) -> Result<Dependencies> {
+		let fabric_api = self
+			.fabric_api_version
+			.split('+')
+			.next()
+			.unwrap_or(&self.fabric_api_version);
+
+		Ok(Dependencies::from([
+			(
+				"fabricloader".to_string(),
+				format!(">={}", self.fabric_loader_version).into(),
+			),
+			("fabric-api".to_string(), format!(">={}", fabric_api).into()),
+			(
+				"minecraft".to_string(),
+				self.minecraft_range.range(&self.minecraft_version),
+			),
+			(
+				"java".to_string(),
+				format!(">={}", get_java_version(&self.minecraft_version)?).into(),
+			),
+		]))
+	}
+}
 
-	let version_manager = VersionManager::new();
-	let versions = version_manager.fetch_all_versions()?;
+/// Asks every question, then lets the user review and change the answers.
+/// Returns `None` if the user decides not to create the project.
+pub fn prompt() -> Result<Option<UserInput>> {
+	let mut prompter = Prompter::new()?;
+	let mut input = UserInput::default();
 
 	println!(
 		"\n{}",
 		"🚀 Let's set up your Fabric mod project!".cyan().bold()
 	);
-
-	let location = project_location::prompt(&render_config)?;
-	let maven_group = maven_group::prompt(&render_config)?;
-	let gradle_dsl = gradle_dsl::prompt(&render_config)?;
-
 	println!(
-		"\n{}",
-		"📦 Now, let's choose your mod's dependencies:"
-			.green()
-			.bold()
+		"{}",
+		"Press Esc to go back to the previous question.".dark_grey()
 	);
 
-	let minecraft_version = Select::new(
-		&"Minecraft version:".bold().to_string(),
-		versions
-			.minecraft
-			.into_iter()
-			.filter(|(_, stable)| *stable)
-			.map(|(v, _)| v)
-			.collect(),
-	)
-	.with_render_config(render_config.clone())
-	.prompt()?;
-
-	let compatible_yarn_versions =
-		version_manager.get_compatible_yarn_versions(&minecraft_version)?;
-	let yarn_version = Select::new(
-		&"Yarn mappings version:".bold().to_string(),
-		compatible_yarn_versions,
-	)
-	.with_render_config(render_config.clone())
-	.prompt()?;
-
-	let fabric_loader_version = Select::new(
-		&"Fabric Loader version:".bold().to_string(),
-		versions
-			.loader
-			.into_iter()
-			.filter(|(_, stable)| *stable)
-			.map(|(v, _)| v)
-			.collect(),
-	)
-	.with_render_config(render_config.clone())
-	.prompt()?;
-
-	let compatible_fabric_api_versions =
-		version_manager.get_compatible_fabric_api_versions(&minecraft_version)?;
-	let fabric_api_version = Select::new(
-		&"Fabric API version:".bold().to_string(),
-		compatible_fabric_api_versions,
-	)
-	.with_render_config(render_config.clone())
-	.prompt()?;
+	// Indices into `Field::ALL` of the questions answered so far, so Esc can
+	// return to the last one that was actually asked.
+	let mut asked: Vec<usize> = Vec::new();
+	let mut index = 0;
+	while let Some(&field) = Field::ALL.get(index) {
+		if !field.applies_to(&input) {
+			index += 1;
+			continue;
+		}
+		if let Some(section) = field.section().filter(|_| !prompter.is_answered(field)) {
+			println!("\n{}", section);
+		}
 
-	println!(
-		"\n{}",
-		"📝 Let's add some details about your mod:".yellow().bold()
-	);
+		match prompter.ask(&mut input, field) {
+			Ok(()) => {
+				asked.push(index);
+				index += 1;
+			}
+			Err(e) if is_canceled(&e) => {
+				// Esc on the first question asks it again.
+				if let Some(previous) = asked.pop() {
+					index = previous;
+				}
+			}
+			Err(e) => return Err(e),
+		}
+	}
 
-	let version = Text::new(&"Mod version:".bold().to_string())
-		.with_default("0.1.0")
-		.with_render_config(render_config.clone())
-		.prompt()?;
-	let description = Text::new(&"Mod description:".bold().to_string())
-		.with_render_config(render_config.clone())
-		.prompt()?;
-	let author = Text::new(&"Mod author:".bold().to_string())
-		.with_render_config(render_config.clone())
-		.prompt()?;
-	let license = Text::new(&"Mod license:".bold().to_string())
-		.with_default("MIT")
-		.with_render_config(render_config.clone())
-		.prompt()?;
+	let Some(mut input) = prompter.review(input)? else {
+		return Ok(None);
+	};
+	input.publishing.minecraft_versions = input
+		.minecraft_range
+		.releases(&input.minecraft_version, &prompter.versions.minecraft);
+
+	Ok(Some(input))
+}
+
+const CREATE: &str = "Create the project";
+const CHANGE: &str = "Change an answer";
+const CANCEL: &str = "Cancel";
+
+/// State shared by the questions: fetched versions, the user's saved defaults
+/// and which questions have been answered so far.
+struct Prompter {
+	render_config: RenderConfig<'static>,
+	version_manager: VersionManager,
+	versions: VersionData,
+	config: Config,
+	answered: Vec<Field>,
+}
+
+impl Prompter {
+	fn new() -> Result<Self> {
+		let version_manager = VersionManager::new();
+		let versions = version_manager.fetch_all_versions()?;
+
+		Ok(Self {
+			render_config: render_config(),
+			version_manager,
+			versions,
+			config: Config::load()?,
+			answered: Vec::new(),
+		})
+	}
+
+	fn is_answered(&self, field: Field) -> bool {
+		self.answered.contains(&field)
+	}
+
+	/// Shows a summary of the answers until the user creates the project or cancels.
+	fn review(&mut self, mut input: UserInput) -> Result<Option<UserInput>> {
+		loop {
+			summary::print(&input)?;
 
-	let git = Confirm::new(&"Initialize Git repository?".bold().to_string())
-		.with_default(false)
-		.with_render_config(render_config)
+			let action = Select::new(
+				&"What would you like to do?".bold().to_string(),
+				vec![CREATE, CHANGE, CANCEL],
+			)
+			.with_render_config(self.render_config)
+			.prompt();
+
+			// There is no earlier question to go back to, so Esc shows the
+			// summary again rather than dropping every answer.
+			match action {
+				Ok(CREATE) => return Ok(Some(input)),
+				Ok(CANCEL) => return Ok(None),
+				Ok(_) => {}
+				Err(InquireError::OperationCanceled) => continue,
+				Err(e) => return Err(e.into()),
+			}
+
+			let fields: Vec<Field> = Field::ALL
+				.into_iter()
+				.filter(|field| field.applies_to(&input))
+				.collect();
+			let options = fields
+				.iter()
+				.map(|field| format!("{}: {}", field.label(), field.value(&input)))
+				.collect();
+			let choice = Select::new(
+				&"Which answer? (type to search, Esc to go back)"
+					.bold()
+					.to_string(),
+				options,
+			)
+			.with_page_size(10)
+			.with_render_config(self.render_config)
+			.raw_prompt();
+			let field = match choice {
+				Ok(choice) => fields[choice.index],
+				Err(InquireError::OperationCanceled) => continue,
+				Err(e) => return Err(e.into()),
+			};
+
+			// Esc while changing an answer keeps the old one, including the
+			// answers that depend on it.
+			let mut changed = input.clone();
+			match self.change(&mut changed, field) {
+				Ok(()) => input = changed,
+				Err(e) if is_canceled(&e) => {}
+				Err(e) => return Err(e),
+			}
+		}
+	}
+
+	/// Asks `field` again, followed by the questions that depend on its answer.
+	fn change(&mut self, input: &mut UserInput, field: Field) -> Result<()> {
+		self.ask(input, field)?;
+		for &dependent in field.dependents() {
+			if dependent.applies_to(input) {
+				self.ask(input, dependent)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	fn ask(&mut self, input: &mut UserInput, field: Field) -> Result<()> {
+		let render_config = self.render_config;
+		let answered = self.is_answered(field);
+
+		match field {
+			Field::Location => {
+				input.location =
+					project_location::prompt(&render_config, answered.then_some(&input.location))?
+			}
+			Field::MavenGroup => {
+				let default = if answered {
+					Some(input.maven_group.as_str())
+				} else {
+					self.config.maven_group.as_deref()
+				};
+				input.maven_group = maven_group::prompt(&render_config, default)?;
+			}
+			Field::Package => {
+				let current = answered.then_some(input.package.as_str());
+				let package = maven_group::prompt_package(
+					&render_config,
+					&input.maven_group,
+					input.mod_id(),
+					current,
+				)?;
+				input.package = package;
+			}
+			Field::GradleDsl => {
+				let default = if answered {
+					Some(input.gradle_dsl)
+				} else {
+					self.config.gradle_dsl
+				};
+				input.gradle_dsl = gradle_dsl::prompt(&render_config, default)?;
+			}
+			Field::VersionCatalog => {
+				input.version_catalog = Confirm::new(
+					&"Use a version catalog (libs.versions.toml)?"
+						.bold()
+						.to_string(),
+				)
+				.with_default(if answered {
+					input.version_catalog
+				} else {
+					matches!(input.gradle_dsl, GradleDsl::Kotlin)
+				})
+				.with_render_config(render_config)
+				.prompt()?;
+			}
+			Field::GradleSettings => {
+				let current = if answered {
+					input.gradle_settings.clone()
+				} else {
+					self.config.gradle.clone()
+				};
+				input.gradle_settings = self.prompt_gradle_settings(&current)?;
+			}
+			Field::MinecraftVersion => {
+				let versions = version_select::minecraft_versions(&self.versions.minecraft);
+				input.minecraft_version = version_select::prompt(
+					&render_config,
+					"Minecraft version:",
+					&versions,
+					answered.then_some(input.minecraft_version.as_str()),
+				)?;
+			}
+			Field::MinecraftRange => {
+				input.minecraft_range =
+					minecraft_range::prompt(&render_config, &input.minecraft_version)?
+			}
+			Field::Yarn => {
+				let versions = self
+					.version_manager
+					.get_compatible_yarn_versions(&input.minecraft_version)?;
+				input.yarn_version = version_select::prompt(
+					&render_config,
+					"Yarn mappings version:",
+					&all_stable(versions),
+					answered.then_some(input.yarn_version.as_str()),
+				)?;
+			}
+			Field::Loader => {
+				let current = if answered {
+					Some(input.fabric_loader_version.as_str())
+				} else {
+					match self.config.loader.unwrap_or_default() {
+						LoaderChannel::Stable => None,
+						// Versions are listed newest first, betas included.
+						LoaderChannel::Latest => self
+							.versions
+							.loader
+							.first()
+							.map(|(version, _)| version.as_str()),
+					}
+				};
+				input.fabric_loader_version = version_select::prompt(
+					&render_config,
+					"Fabric Loader version:",
+					&self.versions.loader,
+					current,
+				)?;
+			}
+			Field::FabricApi => {
+				let versions = self
+					.version_manager
+					.get_compatible_fabric_api_versions(&input.minecraft_version)?;
+				input.fabric_api_version = version_select::prompt(
+					&render_config,
+					"Fabric API version:",
+					&all_stable(versions),
+					answered.then_some(input.fabric_api_version.as_str()),
+				)?;
+			}
+			Field::Version => {
+				let message = "Mod version:".bold().to_string();
+				let mut prompt = Text::new(&message)
+					.with_default("0.1.0")
+					.with_render_config(render_config);
+				prompt.initial_value = answered.then_some(input.version.as_str());
+				input.version = prompt.prompt()?;
+			}
+			Field::Description => {
+				let message = "Mod description:".bold().to_string();
+				let mut prompt = Text::new(&message).with_render_config(render_config);
+				prompt.initial_value = answered.then_some(input.description.as_str());
+				input.description = prompt.prompt()?;
+			}
+			Field::Authors => {
+				let message = "Mod authors (comma-separated):".bold().to_string();
+				let current = input.authors.join(", ");
+				let mut prompt = Text::new(&message)
+					.with_validator(|authors: &str| {
+						Ok(if split_names(authors).is_empty() {
+							Validation::Invalid("At least one author is required".into())
+						} else {
+							Validation::Valid
+						})
+					})
+					.with_render_config(render_config);
+				prompt.default = self.config.author.as_deref();
+				prompt.initial_value = answered.then_some(current.as_str());
+				input.authors = split_names(&prompt.prompt()?);
+			}
+			Field::Contributors => {
+				let message = "Contributors (comma-separated, optional):"
+					.bold()
+					.to_string();
+				let current = input.contributors.join(", ");
+				let mut prompt = Text::new(&message).with_render_config(render_config);
+				prompt.initial_value = answered.then_some(current.as_str());
+				input.contributors = split_names(&prompt.prompt()?);
+			}
+			Field::Contact => input.contact = contact::prompt(&render_config, &input.contact)?,
+			Field::License => {
+				let default = if answered {
+					Some(input.license.as_str())
+				} else {
+					self.config.license.as_deref()
+				};
+				input.license = license::prompt(&render_config, default)?;
+			}
+			Field::Icon => input.icon = icon::prompt(&render_config, input.icon.as_deref())?,
+			Field::Locales => input.locales = locales::prompt(&render_config, &input.locales)?,
+			Field::Mixin => {
+				input.mixin = confirm(&render_config, "Add an example mixin?", input.mixin)?
+			}
+			Field::Datagen => {
+				input.datagen = confirm(&render_config, "Set up data generation?", input.datagen)?
+			}
+			Field::AccessWidener => {
+				input.access_widener = confirm(
+					&render_config,
+					"Add an access widener?",
+					input.access_widener,
+				)?
+			}
+			Field::Junit => {
+				input.junit = confirm(&render_config, "Set up JUnit tests?", input.junit)?
+			}
+			Field::Gametest => {
+				input.gametest = confirm(&render_config, "Set up gametests?", input.gametest)?
+			}
+			Field::Publishing => {
+				input.publishing = publishing::prompt(&render_config, &input.publishing)?
+			}
+			Field::Git => {
+				let default = if answered {
+					input.git
+				} else {
+					self.config.git.unwrap_or(false)
+				};
+				input.git = confirm(&render_config, "Initialize Git repository?", default)?;
+				if !input.git {
+					input.ci = CiProvider::None;
+				}
+			}
+			Field::Ci => input.ci = ci_provider::prompt(&render_config)?,
+			Field::Ides => input.ides = ide::prompt(&render_config, &input.ides)?,
+			Field::Decompiler => {
+				let current = if answered {
+					input.gradle_settings.decompiler
+				} else {
+					self.config.gradle.decompiler
+				};
+				input.gradle_settings.decompiler =
+					gradle_settings::prompt_decompiler(&render_config, current)?;
+			}
+		}
+
+		if !answered {
+			self.answered.push(field);
+		}
+
+		Ok(())
+	}
+
+	/// Asks for the Gradle settings, starting from `current`, and offers to save
+	/// settings that differ from the user's defaults as the new defaults.
+	fn prompt_gradle_settings(&mut self, current: &GradleSettings) -> Result<GradleSettings> {
+		let gradle_settings = gradle_settings::prompt(&self.render_config, current)?;
+		// The decompiler isn't asked for here, so the saved one stays as it is.
+		let defaults = GradleSettings {
+			decompiler: self.config.gradle.decompiler,
+			..gradle_settings.clone()
+		};
+		if defaults == self.config.gradle {
+			return Ok(gradle_settings);
+		}
+
+		let save = Confirm::new(
+			&"Save these Gradle settings as your defaults?"
+				.bold()
+				.to_string(),
+		)
+		.with_default(true)
+		.with_render_config(self.render_config)
 		.prompt()?;
+		if save {
+			self.config.gradle = defaults;
+			self.config.save()?;
+		}
 
-	println!(
-		"\n{}",
-		"✨ Great! Your mod project is ready to be created."
-			.magenta()
-			.bold()
-	);
+		Ok(gradle_settings)
+	}
+}
 
-	Ok(UserInput {
-		location,
-		maven_group,
-		gradle_dsl,
-		minecraft_version,
-		yarn_version,
-		fabric_loader_version,
-		fabric_api_version,
-		version,
-		description,
-		author,
-		license,
-		git,
+fn confirm(render_config: &RenderConfig, message: &str, default: bool) -> Result<bool> {
+	Confirm::new(&message.bold().to_string())
+		.with_default(default)
+		.with_render_config(*render_config)
+		.prompt()
+		.map_err(Into::into)
+}
+
+/// Yarn and Fabric API builds have no stability flag; every build for a
+/// Minecraft version is offered.
+fn all_stable(versions: Vec<String>) -> Vec<(String, bool)> {
+	versions
+		.into_iter()
+		.map(|version| (version, true))
+		.collect()
+}
+
+/// Whether the user pressed Esc to leave a prompt.
+fn is_canceled(error: &anyhow::Error) -> bool {
+	matches!(
+		error.downcast_ref::<InquireError>(),
+		Some(InquireError::OperationCanceled)
+	)
+}
+
+/// Accepts the single `author` string written by older manifests as well as a list.
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum OneOrMany {
+		One(String),
+		Many(Vec<String>),
+	}
+
+	Ok(match OneOrMany::deserialize(deserializer)? {
+		OneOrMany::One(name) => vec![name],
+		OneOrMany::Many(names) => names,
 	})
 }
 
+fn split_names(names: &str) -> Vec<String> {
+	names
+		.split(',')
+		.map(str::trim)
+		.filter(|name| !name.is_empty())
+		.map(String::from)
+		.collect()
+}
+
+pub fn render_config() -> RenderConfig<'static> {
+	RenderConfig::default()
+		.with_prompt_prefix(Styled::new("›").with_fg(Color::DarkMagenta))
+		.with_answered_prompt_prefix(Styled::new("•").with_fg(Color::LightMagenta))
+		.with_scroll_up_prefix(Styled::new("↑").with_fg(Color::DarkMagenta))
+		.with_scroll_down_prefix(Styled::new("↓").with_fg(Color::DarkMagenta))
+}
+
 pub fn warn_render_config() -> RenderConfig<'static> {
 	RenderConfig::default()
 		.with_prompt_prefix(Styled::new("⚠").with_fg(inquire::ui::Color::DarkYellow))
@@ -1,104 +1,294 @@
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use crossterm::style::Stylize;
-use dunce::canonicalize;
 use inquire::{ui::RenderConfig, validator::Validation, Confirm, Text};
-use std::{fs, path::PathBuf};
+use serde::{Deserialize, Serialize};
+use std::{env, error::Error, path::PathBuf};
 
 use super::warn_render_config;
+use crate::utils::{is_java_identifier, sanitize_mod_id, MAX_MOD_ID_LENGTH};
 
-const DEFAULT_NAME: &str = "MyMod";
-const VALID_CHARS: &[char] = &[
-	'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
-	't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L',
-	'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '0', '1', '2', '3', '4',
-	'5', '6', '7', '8', '9',
-];
+const DEFAULT_NAME: &str = "My Mod";
+/// Characters that aren't allowed in directory names on at least one platform.
+const RESERVED_PATH_CHARS: &[char] = &['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
 
-#[derive(Debug)]
+/// The names of the mod and where it is generated. Real mods often use a
+/// different spelling for each, e.g. `Better Combat`, `bettercombat`,
+/// `BetterCombat` and `better-combat`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct ProjectLocation {
+	/// The display name, shown in Mod Menu and launchers.
 	pub mod_name: String,
+	#[serde(default)]
+	pub mod_id: String,
+	/// The name of the main class.
+	#[serde(default)]
+	pub class_name: String,
+	/// Absolute and machine-specific, so it stays out of the manifest.
+	#[serde(skip)]
 	pub path: PathBuf,
 }
 
-impl ProjectLocation {
-	pub fn new(mod_name: &str) -> Result<Self> {
-		let sanitized_name = sanitize_mod_name(mod_name);
-		let path = PathBuf::from(&sanitized_name);
+/// Asks for the display name, then for the mod ID, class name and directory,
+/// suggesting values derived from the name. `current` holds earlier answers,
+/// which are suggested instead as long as the name stays the same.
+pub fn prompt(
+	render_config: &RenderConfig,
+	current: Option<&ProjectLocation>,
+) -> Result<ProjectLocation> {
+	let message = "What's the name of your Fabric mod?".bold().to_string();
+	let mut name_prompt = Text::new(&message)
+		.with_default(DEFAULT_NAME)
+		.with_validator(|name: &str| {
+			Ok(if name.trim().is_empty() {
+				Validation::Invalid("The name cannot be empty".into())
+			} else {
+				Validation::Valid
+			})
+		})
+		.with_render_config(*render_config);
+	name_prompt.initial_value = current.map(|current| current.mod_name.as_str());
+	let mod_name = name_prompt.prompt()?.trim().to_string();
 
-		if path.exists() && path.is_file() {
-			return Err(anyhow!(
-				"'{}' is an existing file, please provide a valid mod name",
-				mod_name
-			));
+	let current = current.filter(|current| current.mod_name == mod_name);
+	let (mod_id, class_name, folder) = match current {
+		Some(current) => (
+			current.mod_id.clone(),
+			current.class_name.clone(),
+			current
+				.path
+				.file_name()
+				.map(|folder| folder.to_string_lossy().into_owned())
+				.unwrap_or_default(),
+		),
+		None => {
+			let mod_id = derive_mod_id(&mod_name);
+			(mod_id.clone(), derive_class_name(&mod_name), mod_id)
 		}
+	};
 
-		let canonical_path = if path.exists() {
-			canonicalize(&path)?
-		} else {
-			fs::create_dir_all(&path)?;
-			let res = canonicalize(&path);
-			fs::remove_dir(&path)?;
-			res?
-		};
-
-		let location = Self {
-			mod_name: sanitized_name,
-			path: canonical_path,
-		};
-		location.validate()?;
-
-		Ok(location)
-	}
+	let message = "Mod ID:".bold().to_string();
+	let mod_id = suggest(
+		Text::new(&message)
+			.with_help_message("Lowercase letters, digits, '-' and '_', e.g. bettercombat")
+			.with_validator(validate_mod_id)
+			.with_render_config(*render_config),
+		&mod_id,
+		validate_mod_id,
+	)
+	.prompt()?;
+
+	let message = "Main class name:".bold().to_string();
+	let class_name = suggest(
+		Text::new(&message)
+			.with_validator(validate_class_name)
+			.with_render_config(*render_config),
+		&class_name,
+		validate_class_name,
+	)
+	.prompt()?;
 
-	fn validate(&self) -> Result<()> {
-		if self.mod_name.chars().all(|c| VALID_CHARS.contains(&c)) {
-			Ok(())
-		} else {
-			Err(anyhow!(
-				"Mod name '{}' must only contain alphanumeric characters",
-				self.mod_name
-			))
+	let message = "Project directory:".bold().to_string();
+	let path = loop {
+		let folder = suggest(
+			Text::new(&message)
+				.with_validator(validate_folder)
+				.with_render_config(*render_config),
+			&folder,
+			validate_folder,
+		)
+		.prompt()?;
+		let path = env::current_dir()?.join(folder.trim());
+
+		if !path.exists() {
+			break path;
 		}
+
+		let should_continue = Confirm::new(&format!(
+			"{}",
+			format!(
+				"'{}' already exists. Do you want to use it anyway?",
+				path.display()
+			)
+			.bold()
+		))
+		.with_default(false)
+		.with_render_config(warn_render_config())
+		.prompt()?;
+
+		if should_continue {
+			break path;
+		}
+	};
+
+	Ok(ProjectLocation {
+		mod_name,
+		mod_id,
+		class_name,
+		path,
+	})
+}
+
+type Validator = fn(&str) -> Result<Validation, Box<dyn Error + Send + Sync>>;
+
+/// Offers `value` as the default if it is valid. Defaults skip validation, so
+/// invalid suggestions are pre-filled for editing instead.
+fn suggest<'a>(mut prompt: Text<'a>, value: &'a str, validator: Validator) -> Text<'a> {
+	if matches!(validator(value), Ok(Validation::Valid)) {
+		prompt.default = Some(value);
+	} else {
+		prompt.initial_value = Some(value);
 	}
+	prompt
 }
 
-pub fn prompt(render_config: &RenderConfig) -> Result<ProjectLocation> {
-	let location = loop {
-		let input = Text::new(&format!("{}", "What's the name of your Fabric mod?".bold()))
-			.with_default(DEFAULT_NAME)
-			.with_render_config(*render_config)
-			.with_validator(|input: &str| match ProjectLocation::new(input) {
-				Ok(_) => Ok(Validation::Valid),
-				Err(e) => Ok(Validation::Invalid(e.to_string().into())),
-			})
-			.prompt()?;
+/// Fabric's rule for mod IDs: `^[a-z][a-z0-9-_]{1,63}$`.
+fn validate_mod_id(mod_id: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
+	if mod_id.len() < 2 || mod_id.len() > MAX_MOD_ID_LENGTH {
+		return Ok(Validation::Invalid(
+			format!(
+				"Mod IDs must be between 2 and {} characters long",
+				MAX_MOD_ID_LENGTH
+			)
+			.into(),
+		));
+	}
 
-		let location = ProjectLocation::new(&input)?;
+	if !mod_id.starts_with(|c: char| c.is_ascii_lowercase()) {
+		return Ok(Validation::Invalid(
+			"Mod IDs must start with a lowercase letter".into(),
+		));
+	}
 
-		if location.path.exists() {
-			let should_continue = Confirm::new(&format!(
-				"{}",
-				format!(
-					"'{}' already exists. Do you want to use it anyway?",
-					location.path.display()
-				)
-				.bold()
-			))
-			.with_default(false)
-			.with_render_config(warn_render_config())
-			.prompt()?;
-
-			if should_continue {
-				break location;
-			}
-		} else {
-			break location;
-		}
-	};
+	if !mod_id
+		.chars()
+		.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+	{
+		return Ok(Validation::Invalid(
+			"Mod IDs can only contain lowercase letters, digits, '-' and '_'".into(),
+		));
+	}
 
-	Ok(location)
+	Ok(Validation::Valid)
 }
 
-fn sanitize_mod_name(name: &str) -> String {
-	name.chars().filter(|c| c.is_alphanumeric()).collect()
+fn validate_class_name(class_name: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
+	if !is_java_identifier(class_name) {
+		return Ok(Validation::Invalid(
+			format!("'{}' is not a valid Java class name", class_name).into(),
+		));
+	}
+
+	if !class_name.starts_with(|c: char| c.is_ascii_uppercase())
+		|| !class_name.chars().all(|c| c.is_ascii_alphanumeric())
+	{
+		return Ok(Validation::Invalid(
+			"Use PascalCase: an uppercase letter followed by letters and digits".into(),
+		));
+	}
+
+	Ok(Validation::Valid)
+}
+
+fn validate_folder(folder: &str) -> Result<Validation, Box<dyn Error + Send + Sync>> {
+	let folder = folder.trim();
+	if folder.is_empty() || folder == "." || folder == ".." {
+		return Ok(Validation::Invalid("Enter a directory name".into()));
+	}
+
+	if folder.contains(RESERVED_PATH_CHARS) || folder.chars().any(char::is_control) {
+		return Ok(Validation::Invalid(
+			format!(
+				"Directory names cannot contain any of {}",
+				RESERVED_PATH_CHARS.iter().collect::<String>()
+			)
+			.into(),
+		));
+	}
+
+	if PathBuf::from(folder).is_file() {
+		return Ok(Validation::Invalid(
+			format!("'{}' is an existing file", folder).into(),
+		));
+	}
+
+	Ok(Validation::Valid)
+}
+
+fn derive_mod_id(name: &str) -> String {
+	sanitize_mod_id(name)
+		.chars()
+		.take(MAX_MOD_ID_LENGTH)
+		.collect()
+}
+
+/// `better combat` and `better-combat` both become `BetterCombat`.
+fn derive_class_name(name: &str) -> String {
+	name.split(|c: char| !c.is_ascii_alphanumeric())
+		.flat_map(|word| {
+			let mut chars = word.chars();
+			chars
+				.next()
+				.map(|first| first.to_ascii_uppercase())
+				.into_iter()
+				.chain(chars)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn is_valid(validation: Result<Validation, Box<dyn Error + Send + Sync>>) -> bool {
+		matches!(validation, Ok(Validation::Valid))
+	}
+
+	#[test]
+	fn mod_id_follows_fabric_rule() {
+		for (mod_id, valid) in [
+			("a", false),
+			("ab", true),
+			(&"a".repeat(64), true),
+			(&"a".repeat(65), false),
+			("1mod", false),
+			("-mod", false),
+			("_mod", false),
+			("MyMod", false),
+			("my-mod", true),
+			("my_mod", true),
+			("my_mod-2", true),
+			("my mod", false),
+			("my.mod", false),
+		] {
+			assert_eq!(is_valid(validate_mod_id(mod_id)), valid, "{}", mod_id);
+		}
+	}
+
+	#[test]
+	fn class_name_is_pascal_case_java_identifier() {
+		for (class_name, valid) in [
+			("BetterCombat", true),
+			("Mod2", true),
+			("betterCombat", false),
+			("Better_Combat", false),
+			("Better Combat", false),
+			("2Better", false),
+			("", false),
+		] {
+			assert_eq!(
+				is_valid(validate_class_name(class_name)),
+				valid,
+				"{}",
+				class_name
+			);
+		}
+	}
+
+	#[test]
+	fn names_are_derived_from_display_name() {
+		assert_eq!(derive_mod_id("Better Combat"), "bettercombat");
+		assert_eq!(derive_class_name("Better Combat"), "BetterCombat");
+		assert_eq!(derive_class_name("better-combat"), "BetterCombat");
+		assert_eq!(derive_mod_id(&"a".repeat(70)).len(), MAX_MOD_ID_LENGTH);
+	}
 }
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use crossterm::style::{style, Stylize};
+use inquire::{ui::RenderConfig, validator::Validation, MultiSelect, Text};
+use serde::{Deserialize, Serialize};
+
+const MODRINTH: &str = "Modrinth";
+const CURSEFORGE: &str = "CurseForge";
+
+/// Project IDs on the platforms releases get published to.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Publishing {
+	pub modrinth: Option<String>,
+	pub curseforge: Option<String>,
+	/// The game versions releases are listed under. Not asked for but derived
+	/// from the Minecraft range, so it isn't saved either.
+	#[serde(skip)]
+	pub minecraft_versions: Vec<String>,
+}
+
+impl Publishing {
+	pub fn is_enabled(&self) -> bool {
+		self.modrinth.is_some() || self.curseforge.is_some()
+	}
+}
+
+/// Asks where to publish, starting from the `current` platforms and project IDs.
+pub fn prompt(render_config: &RenderConfig, current: &Publishing) -> Result<Publishing> {
+	println!("{}", style("Publish releases to:").bold());
+
+	let selected: Vec<usize> = [&current.modrinth, &current.curseforge]
+		.into_iter()
+		.enumerate()
+		.filter(|(_, project_id)| project_id.is_some())
+		.map(|(index, _)| index)
+		.collect();
+	let platforms = MultiSelect::new("", vec![MODRINTH, CURSEFORGE])
+		.with_default(&selected)
+		.with_render_config(*render_config)
+		.prompt()?;
+
+	let mut publishing = Publishing::default();
+
+	if platforms.contains(&MODRINTH) {
+		let message = "Modrinth project slug or ID:".bold().to_string();
+		let mut prompt = Text::new(&message)
+			.with_validator(|id: &str| {
+				Ok(if id.trim().is_empty() {
+					Validation::Invalid("Project slug or ID cannot be empty".into())
+				} else {
+					Validation::Valid
+				})
+			})
+			.with_render_config(*render_config);
+		prompt.initial_value = current.modrinth.as_deref();
+		publishing.modrinth = Some(prompt.prompt()?.trim().to_string());
+	}
+
+	if platforms.contains(&CURSEFORGE) {
+		let message = "CurseForge project ID:".bold().to_string();
+		let mut prompt = Text::new(&message)
+			.with_validator(|id: &str| {
+				Ok(
+					if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) {
+						Validation::Valid
+					} else {
+						Validation::Invalid("CurseForge project IDs are numeric".into())
+					},
+				)
+			})
+			.with_render_config(*render_config);
+		prompt.initial_value = current.curseforge.as_deref();
+		publishing.curseforge = Some(prompt.prompt()?);
+	}
+
+	Ok(publishing)
+}
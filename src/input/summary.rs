@@ -0,0 +1,56 @@
+use super::{
+	field::{display_range, Field},
+	UserInput,
+};
+use crate::utils::get_java_version;
+use anyhow::Result;
+use crossterm::style::Stylize;
+
+/// Prints every answer, the values derived from them and the dependency ranges
+/// `fabric.mod.json` will declare.
+pub fn print(input: &UserInput) -> Result<()> {
+	let answers: Vec<(&str, String)> = Field::ALL
+		.into_iter()
+		.filter(|field| field.applies_to(input))
+		.map(|field| (field.label(), field.value(input)))
+		.collect();
+
+	let derived = vec![
+		("Mod ID", input.mod_id().to_string()),
+		("Class name", input.class_name().to_string()),
+		(
+			"Java version",
+			get_java_version(&input.minecraft_version)?.to_string(),
+		),
+		("Output path", input.location.path.display().to_string()),
+	];
+
+	let dependencies = input.dependencies()?;
+	let dependencies: Vec<(&str, String)> = dependencies
+		.iter()
+		.map(|(id, range)| (id.as_str(), display_range(range)))
+		.collect();
+
+	let width = answers
+		.iter()
+		.chain(&derived)
+		.chain(&dependencies)
+		.map(|(label, _)| label.len())
+		.max()
+		.unwrap_or(0);
+
+	println!("\n{}", "🔍 Here's what will be created:".magenta().bold());
+	for (heading, rows) in [
+		("Answers", &answers),
+		("Derived", &derived),
+		("Dependencies in fabric.mod.json", &dependencies),
+	] {
+		println!("\n{}", heading.bold());
+		for (label, value) in rows {
+			println!("  {:<width$}  {}", label, value.as_str().green());
+		}
+	}
+	println!();
+
+	Ok(())
+}
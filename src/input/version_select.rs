@@ -0,0 +1,132 @@
+use crate::utils::{is_supported_minecraft_version, is_weekly_snapshot};
+use anyhow::Result;
+use crossterm::style::Stylize;
+use inquire::{ui::RenderConfig, Select};
+use std::fmt;
+
+const PAGE_SIZE: usize = 10;
+const HELP: &str = "↑↓ to move, type to filter, e.g. \"latest\"";
+
+#[derive(Debug, Clone)]
+enum Choice {
+	Version {
+		version: String,
+		stable: bool,
+		latest: bool,
+	},
+	ShowUnstable,
+	HideUnstable,
+}
+
+impl fmt::Display for Choice {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Choice::Version {
+				version,
+				stable,
+				latest,
+			} => {
+				let kind = (!stable).then(|| unstable_kind(version));
+				match (latest, kind) {
+					(false, None) => f.write_str(version),
+					(true, None) => write!(f, "{} (latest)", version),
+					(false, Some(kind)) => write!(f, "{} ({})", version, kind),
+					(true, Some(kind)) => write!(f, "{} (latest {})", version, kind),
+				}
+			}
+			Choice::ShowUnstable => f.write_str("Show snapshots, pre-releases and unstable builds"),
+			Choice::HideUnstable => f.write_str("Hide snapshots, pre-releases and unstable builds"),
+		}
+	}
+}
+
+/// Asks for one of `versions`, given newest first with whether each is stable.
+/// Unstable versions are hidden behind a toggle unless `current` is one of them.
+pub fn prompt(
+	render_config: &RenderConfig,
+	message: &str,
+	versions: &[(String, bool)],
+	current: Option<&str>,
+) -> Result<String> {
+	let has_unstable = versions.iter().any(|(_, stable)| !stable);
+	let mut show_unstable = current.is_some_and(|current| {
+		versions
+			.iter()
+			.any(|(version, stable)| version == current && !stable)
+	});
+
+	loop {
+		let mut choices = Vec::new();
+		let mut latest_stable = true;
+		let mut latest_unstable = true;
+		for (version, stable) in versions {
+			if !stable && !show_unstable {
+				continue;
+			}
+
+			let latest = if *stable {
+				std::mem::take(&mut latest_stable)
+			} else {
+				std::mem::take(&mut latest_unstable)
+			};
+			choices.push(Choice::Version {
+				version: version.clone(),
+				stable: *stable,
+				latest,
+			});
+		}
+
+		let cursor = current
+			.and_then(|current| {
+				choices.iter().position(
+					|choice| matches!(choice, Choice::Version { version, .. } if version == current),
+				)
+			})
+			.unwrap_or(0);
+
+		if has_unstable {
+			choices.push(if show_unstable {
+				Choice::HideUnstable
+			} else {
+				Choice::ShowUnstable
+			});
+		}
+
+		let choice = Select::new(&message.bold().to_string(), choices)
+			.with_page_size(PAGE_SIZE)
+			.with_help_message(HELP)
+			.with_starting_cursor(cursor)
+			.with_render_config(*render_config)
+			.prompt()?;
+
+		match choice {
+			Choice::Version { version, .. } => return Ok(version),
+			Choice::ShowUnstable => show_unstable = true,
+			Choice::HideUnstable => show_unstable = false,
+		}
+	}
+}
+
+/// Minecraft versions with known Java, Loom and Gradle versions. Snapshots get
+/// those of the release they lead up to; other unstable versions, such as
+/// April Fools' snapshots, and releases newer than the toolchain table are
+/// left out.
+pub fn minecraft_versions(versions: &[(String, bool)]) -> Vec<(String, bool)> {
+	versions
+		.iter()
+		.filter(|(version, _)| is_supported_minecraft_version(version))
+		.cloned()
+		.collect()
+}
+
+fn unstable_kind(version: &str) -> &'static str {
+	if is_weekly_snapshot(version) || version.contains("snapshot") {
+		"snapshot"
+	} else if version.contains("-pre") {
+		"pre-release"
+	} else if version.contains("-rc") {
+		"release candidate"
+	} else {
+		"unstable"
+	}
+}
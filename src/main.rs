@@ -1,14 +1,39 @@
 mod args;
+mod commands;
+mod config;
+mod features;
 mod input;
+mod manifest;
+mod project;
 mod scaffold;
 mod utils;
 mod versions;
 
 use anyhow::Result;
+use args::{Args, Command};
+use clap::Parser;
 
 fn main() -> Result<()> {
+	let args = Args::parse();
 
-	let input = input::prompt()?;
+	match args.command {
+		None => create(),
+		Some(Command::Update { path, yes }) => commands::update::run(&path, yes),
+		Some(Command::Port {
+			minecraft_version,
+			path,
+			yes,
+		}) => commands::port::run(&minecraft_version, &path, yes),
+		Some(Command::Doctor { path }) => commands::doctor::run(&path),
+		Some(Command::Add { feature, path }) => commands::add::run(feature, &path),
+		Some(Command::Config { action }) => commands::config::run(action),
+	}
+}
+
+fn create() -> Result<()> {
+	let Some(input) = input::prompt()? else {
+		return Ok(());
+	};
 
 	scaffold::create(input)?;
 
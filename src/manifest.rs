@@ -0,0 +1,102 @@
+use crate::input::{gradle_dsl::GradleDsl, UserInput};
+use crate::project::dependency_versions::DependencyVersions;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+pub const MANIFEST_FILE: &str = ".modweave.toml";
+
+const LOADER: &str = "fabric";
+const TEMPLATE_SET: &str = "fabric";
+
+/// Records how a project was generated, so later commands don't have to
+/// reverse-engineer it from `gradle.properties`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Manifest {
+	pub modweave_version: String,
+	pub loader: String,
+	pub gradle_dsl: GradleDsl,
+	pub template_set: String,
+	pub versions: ManifestVersions,
+	pub input: UserInput,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ManifestVersions {
+	pub minecraft: String,
+	pub yarn: String,
+	pub fabric_loader: String,
+	pub fabric_api: String,
+}
+
+impl Manifest {
+	pub fn new(input: &UserInput) -> Self {
+		Self {
+			modweave_version: env!("CARGO_PKG_VERSION").to_string(),
+			loader: LOADER.to_string(),
+			gradle_dsl: input.gradle_dsl,
+			template_set: TEMPLATE_SET.to_string(),
+			versions: ManifestVersions {
+				minecraft: input.minecraft_version.clone(),
+				yarn: input.yarn_version.clone(),
+				fabric_loader: input.fabric_loader_version.clone(),
+				fabric_api: input.fabric_api_version.clone(),
+			},
+			input: input.clone(),
+		}
+	}
+
+	pub fn load(project_dir: &Path) -> Result<Option<Self>> {
+		let path = project_dir.join(MANIFEST_FILE);
+		if !path.exists() {
+			return Ok(None);
+		}
+
+		let content = fs::read_to_string(&path)
+			.with_context(|| format!("Failed to read {}", path.display()))?;
+		let mut manifest: Self = toml::from_str(&content)
+			.with_context(|| format!("Failed to parse {}", path.display()))?;
+		manifest.fill_input_versions();
+
+		Ok(Some(manifest))
+	}
+
+	/// Copies the dependency versions currently set in the project into the manifest.
+	pub fn sync_versions(&mut self, versions: &DependencyVersions) {
+		let fields = [
+			("minecraft_version", &mut self.versions.minecraft),
+			("yarn_mappings", &mut self.versions.yarn),
+			("fabric_loader_version", &mut self.versions.fabric_loader),
+			("fabric_api_version", &mut self.versions.fabric_api),
+		];
+
+		for (key, field) in fields {
+			if let Some(value) = versions.get(key) {
+				*field = value.to_string();
+			}
+		}
+		self.fill_input_versions();
+	}
+
+	/// The answers don't store the versions themselves, so they can't
+	/// contradict `[versions]`.
+	fn fill_input_versions(&mut self) {
+		let input = &mut self.input;
+		input.minecraft_version.clone_from(&self.versions.minecraft);
+		input.yarn_version.clone_from(&self.versions.yarn);
+		input
+			.fabric_loader_version
+			.clone_from(&self.versions.fabric_loader);
+		input
+			.fabric_api_version
+			.clone_from(&self.versions.fabric_api);
+	}
+
+	pub fn save(&self, project_dir: &Path) -> Result<()> {
+		let path = project_dir.join(MANIFEST_FILE);
+		let content = toml::to_string_pretty(self).context("Failed to serialize manifest")?;
+
+		fs::write(&path, content)
+			.with_context(|| format!("Failed to write manifest to {}", path.display()))
+	}
+}
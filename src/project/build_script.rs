@@ -0,0 +1,249 @@
+use anyhow::{Context, Result};
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
+
+/// A Groovy or Kotlin build script, edited as text so that the user's own
+/// formatting and additions survive.
+#[derive(Debug)]
+pub struct BuildScript {
+	path: PathBuf,
+	content: String,
+}
+
+impl BuildScript {
+	pub fn load(path: &Path) -> Result<Self> {
+		let content = fs::read_to_string(path)
+			.with_context(|| format!("Failed to read {}", path.display()))?;
+
+		Ok(Self {
+			path: path.to_path_buf(),
+			content,
+		})
+	}
+
+	pub fn save(&self) -> Result<()> {
+		fs::write(&self.path, &self.content)
+			.with_context(|| format!("Failed to write {}", self.path.display()))
+	}
+
+	pub fn contains(&self, text: &str) -> bool {
+		self.content.contains(text)
+	}
+
+	/// Appends a top-level block, separated from the rest of the script by a blank line.
+	pub fn append(&mut self, snippet: &str) {
+		let trimmed_len = self.content.trim_end().len();
+		self.content.truncate(trimmed_len);
+		self.content.push_str("\n\n");
+		self.content.push_str(snippet.trim_end());
+		self.content.push('\n');
+	}
+
+	/// Adds `line` as the last statement of the top-level `block`, creating the
+	/// block at the end of the script if it doesn't exist yet.
+	pub fn insert_into_block(&mut self, block: &str, line: &str) {
+		let Some(close) = self.find_block_end(block) else {
+			self.append(&format!("{} {{\n    {}\n}}", block, line));
+			return;
+		};
+
+		self.content.insert_str(close, &format!("    {}\n", line));
+	}
+
+	/// Returns the offset of the line holding the closing brace of a top-level block.
+	fn find_block_end(&self, block: &str) -> Option<usize> {
+		let header = format!("{} {{", block);
+		let mut offset = 0;
+		let mut depth = 0;
+		let mut inside = false;
+
+		for line in self.content.split_inclusive('\n') {
+			if !inside && line.trim_end() == header {
+				inside = true;
+			}
+
+			if inside {
+				for c in line.chars() {
+					match c {
+						'{' => depth += 1,
+						'}' => depth -= 1,
+						_ => {}
+					}
+				}
+
+				if depth == 0 {
+					return Some(offset);
+				}
+			}
+
+			offset += line.len();
+		}
+
+		None
+	}
+
+	pub fn loom_version(&self) -> Option<&str> {
+		self.content.lines().find_map(plugin_version)
+	}
+
+	pub fn set_loom_version(&mut self, loom_version: &str) {
+		let Some((line, current)) = self
+			.content
+			.lines()
+			.find_map(|line| plugin_version(line).map(|version| (line, version)))
+		else {
+			return;
+		};
+
+		let updated = line.replacen(current, loom_version, 1);
+		self.content = self.content.replacen(line, &updated, 1);
+	}
+
+	pub fn java_version(&self) -> Option<&str> {
+		let start = self.content.find("JavaLanguageVersion.of(")? + "JavaLanguageVersion.of(".len();
+		let len = self.content[start..].find(')')?;
+
+		Some(self.content[start..start + len].trim())
+	}
+
+	/// Rewrites the toolchain, source/target compatibility and `release` values.
+	pub fn set_java_version(&mut self, java_version: &str) {
+		let Some(current) = self.java_version().map(String::from) else {
+			return;
+		};
+
+		let replacements = [
+			("JavaLanguageVersion.of(", ")"),
+			("JavaVersion.VERSION_1_", ""),
+			("JavaVersion.VERSION_", ""),
+			("release = ", ""),
+			("release.set(", ")"),
+		];
+
+		for (prefix, suffix) in replacements {
+			let from = format!("{}{}{}", prefix, current, suffix);
+			let to = if prefix.starts_with("JavaVersion.") {
+				format!("JavaVersion.{}", java_version_constant(java_version))
+			} else {
+				format!("{}{}{}", prefix, java_version, suffix)
+			};
+
+			self.content = self.content.replace(&from, &to);
+		}
+	}
+}
+
+/// The name of Gradle's `JavaVersion` constant, which keeps the legacy `1_`
+/// prefix for Java 8.
+pub fn java_version_constant(java_version: &str) -> String {
+	if java_version == "8" {
+		"VERSION_1_8".to_string()
+	} else {
+		format!("VERSION_{}", java_version)
+	}
+}
+
+/// Extracts the version from a `fabric-loom` plugin declaration in either DSL.
+fn plugin_version(line: &str) -> Option<&str> {
+	let plugin = line.find("fabric-loom")?;
+	let keyword = plugin + line[plugin..].find("version")? + "version".len();
+	let rest = &line[keyword..];
+	let open = rest.find(['\'', '"'])?;
+	let quote = rest[open..].chars().next()?;
+	let len = rest[open + 1..].find(quote)?;
+
+	Some(&rest[open + 1..open + 1 + len])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::scaffold::read_template;
+
+	fn script(content: &str) -> BuildScript {
+		BuildScript {
+			path: PathBuf::new(),
+			content: content.to_string(),
+		}
+	}
+
+	#[test]
+	fn insert_into_block_adds_the_last_statement() {
+		for template in [
+			"gradle/dsl/groovy/build.gradle",
+			"gradle/dsl/kotlin/build.gradle.kts",
+		] {
+			let content = read_template(template).unwrap();
+			let mut build_script = script(&content);
+			build_script.insert_into_block("repositories", "mavenCentral()");
+
+			assert!(
+				build_script
+					.content
+					.contains("    // Add repositories here\n    mavenCentral()\n}\n"),
+				"{}",
+				template
+			);
+			assert_eq!(
+				build_script.content.len(),
+				content.len() + "    mavenCentral()\n".len()
+			);
+		}
+	}
+
+	#[test]
+	fn insert_into_block_skips_nested_blocks_with_the_same_name() {
+		let mut build_script = script("loom {\n    mods {\n    }\n}\n\nmods {\n}\n");
+		build_script.insert_into_block("mods", "example");
+
+		assert_eq!(
+			build_script.content,
+			"loom {\n    mods {\n    }\n}\n\nmods {\n    example\n}\n"
+		);
+	}
+
+	#[test]
+	fn insert_into_block_creates_missing_blocks() {
+		let mut build_script = script("plugins {\n}\n");
+		build_script.insert_into_block("repositories", "mavenCentral()");
+
+		assert_eq!(
+			build_script.content,
+			"plugins {\n}\n\nrepositories {\n    mavenCentral()\n}\n"
+		);
+	}
+
+	#[test]
+	fn loom_version_in_both_dsls() {
+		for line in [
+			"    id 'fabric-loom' version '1.7-SNAPSHOT'",
+			"    id(\"fabric-loom\") version \"1.7-SNAPSHOT\"",
+		] {
+			let mut build_script = script(&format!("plugins {{\n{}\n}}\n", line));
+			assert_eq!(build_script.loom_version(), Some("1.7-SNAPSHOT"));
+
+			build_script.set_loom_version("1.8-SNAPSHOT");
+			assert_eq!(
+				build_script.content,
+				format!("plugins {{\n{}\n}}\n", line.replace("1.7", "1.8"))
+			);
+		}
+	}
+
+	#[test]
+	fn set_java_version_rewrites_every_reference() {
+		let mut build_script = script(
+			"java {\n    toolchain.languageVersion = JavaLanguageVersion.of(17)\n    \
+			 sourceCompatibility = JavaVersion.VERSION_17\n}\n\
+			 tasks.withType(JavaCompile).configureEach {\n    it.options.release = 17\n}\n",
+		);
+		build_script.set_java_version("21");
+
+		assert_eq!(build_script.java_version(), Some("21"));
+		assert!(!build_script.content.contains("17"));
+		assert!(build_script.content.contains("JavaVersion.VERSION_21"));
+		assert!(build_script.content.contains("release = 21"));
+	}
+}
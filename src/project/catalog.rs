@@ -0,0 +1,272 @@
+use anyhow::{Context, Result};
+use std::{fs, path::Path};
+
+/// A `gradle/libs.versions.toml` file that can be edited in place without
+/// losing comments, blank lines or the order of its entries.
+#[derive(Debug)]
+pub struct VersionCatalog {
+	lines: Vec<String>,
+}
+
+impl VersionCatalog {
+	pub fn load(path: &Path) -> Result<Self> {
+		let content = fs::read_to_string(path)
+			.with_context(|| format!("Failed to read {}", path.display()))?;
+
+		Ok(Self::parse(&content))
+	}
+
+	pub fn parse(content: &str) -> Self {
+		Self {
+			lines: content.lines().map(String::from).collect(),
+		}
+	}
+
+	pub fn save(&self, path: &Path) -> Result<()> {
+		fs::write(path, self.to_string())
+			.with_context(|| format!("Failed to write {}", path.display()))
+	}
+
+	/// The value of a `[versions]` entry.
+	pub fn version(&self, alias: &str) -> Option<&str> {
+		let (start, end) = self.section("versions")?;
+
+		self.lines[start..end]
+			.iter()
+			.filter_map(|line| split_version(line))
+			.find(|(a, _)| *a == alias)
+			.map(|(_, value)| value)
+	}
+
+	/// Replaces the value of a `[versions]` entry, or adds a new one.
+	pub fn set_version(&mut self, alias: &str, value: &str) {
+		if let Some((start, end)) = self.section("versions") {
+			for line in &mut self.lines[start..end] {
+				if let Some((a, current)) = split_version(line) {
+					if a == alias {
+						*line = line.replacen(
+							&format!("\"{}\"", current),
+							&format!("\"{}\"", value),
+							1,
+						);
+						return;
+					}
+				}
+			}
+		}
+
+		self.insert("versions", format!("{} = \"{}\"", alias, value));
+	}
+
+	pub fn contains_library(&self, alias: &str) -> bool {
+		self.contains_entry("libraries", alias)
+	}
+
+	/// Adds a `[libraries]` entry whose version refers to a `[versions]` entry.
+	pub fn add_library(&mut self, alias: &str, module: &str, version_ref: &str) {
+		self.insert(
+			"libraries",
+			format!(
+				"{} = {{ module = \"{}\", version.ref = \"{}\" }}",
+				alias, module, version_ref
+			),
+		);
+	}
+
+	/// Adds a `[plugins]` entry whose version refers to a `[versions]` entry.
+	pub fn add_plugin(&mut self, alias: &str, id: &str, version_ref: &str) {
+		self.insert(
+			"plugins",
+			format!(
+				"{} = {{ id = \"{}\", version.ref = \"{}\" }}",
+				alias, id, version_ref
+			),
+		);
+	}
+
+	fn contains_entry(&self, section: &str, alias: &str) -> bool {
+		self.section(section).is_some_and(|(start, end)| {
+			self.lines[start..end]
+				.iter()
+				.filter_map(|line| line.split_once('='))
+				.any(|(a, _)| a.trim() == alias)
+		})
+	}
+
+	/// Adds `entry` after the last entry of `section`, creating the section if needed.
+	fn insert(&mut self, section: &str, entry: String) {
+		let Some((start, end)) = self.section(section) else {
+			if self
+				.lines
+				.last()
+				.is_some_and(|line| !line.trim().is_empty())
+			{
+				self.lines.push(String::new());
+			}
+			self.lines.push(format!("[{}]", section));
+			self.lines.push(entry);
+			return;
+		};
+
+		let position = (start..end)
+			.rev()
+			.find(|&i| !self.lines[i].trim().is_empty())
+			.map_or(start, |i| i + 1);
+		self.lines.insert(position, entry);
+	}
+
+	/// The range of lines between a section's header and the next header.
+	fn section(&self, name: &str) -> Option<(usize, usize)> {
+		let header = format!("[{}]", name);
+		let start = self.lines.iter().position(|line| line.trim() == header)? + 1;
+		let end = self.lines[start..]
+			.iter()
+			.position(|line| line.trim_start().starts_with('['))
+			.map_or(self.lines.len(), |offset| start + offset);
+
+		Some((start, end))
+	}
+}
+
+impl std::fmt::Display for VersionCatalog {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for line in &self.lines {
+			writeln!(f, "{}", line)?;
+		}
+		Ok(())
+	}
+}
+
+/// Turns a library alias into the accessor Gradle generates for it, e.g.
+/// `fabric-api` into `libs.fabric.api`.
+pub fn accessor(alias: &str) -> String {
+	format!("libs.{}", alias.replace(['-', '_'], "."))
+}
+
+pub fn plugin_accessor(alias: &str) -> String {
+	format!("libs.plugins.{}", alias.replace(['-', '_'], "."))
+}
+
+/// Turns a name that may contain dots or other characters Gradle rejects, like
+/// a Modrinth slug, into a valid alias. Aliases have to start with a letter.
+pub fn alias(name: &str) -> String {
+	let alias = name
+		.split(|c: char| !c.is_ascii_alphanumeric())
+		.filter(|part| !part.is_empty())
+		.collect::<Vec<_>>()
+		.join("-")
+		.to_ascii_lowercase();
+
+	if alias.starts_with(|c: char| c.is_ascii_lowercase()) {
+		alias
+	} else {
+		format!("mod-{}", alias).trim_end_matches('-').to_string()
+	}
+}
+
+/// Splits a `alias = "value"` line. Comments, blank lines and table values yield `None`.
+fn split_version(line: &str) -> Option<(&str, &str)> {
+	let trimmed = line.trim_start();
+	if trimmed.is_empty() || trimmed.starts_with('#') {
+		return None;
+	}
+
+	let (alias, value) = line.split_once('=')?;
+	let value = value.trim().strip_prefix('"')?.split('"').next()?;
+
+	Some((alias.trim(), value))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const CONTENT: &str = "\
+[versions]
+# Pinned until the next release.
+minecraft = \"1.21\"
+loom = \"1.7-SNAPSHOT\"
+
+[libraries]
+minecraft = { module = \"com.mojang:minecraft\", version.ref = \"minecraft\" }
+
+[plugins]
+loom = { id = \"fabric-loom\", version.ref = \"loom\" }
+";
+
+	#[test]
+	fn reads_versions_only_from_their_section() {
+		let catalog = VersionCatalog::parse(CONTENT);
+
+		assert_eq!(catalog.version("minecraft"), Some("1.21"));
+		assert_eq!(catalog.version("loom"), Some("1.7-SNAPSHOT"));
+		assert_eq!(catalog.version("missing"), None);
+		assert!(catalog.contains_library("minecraft"));
+		assert!(!catalog.contains_library("loom"));
+	}
+
+	#[test]
+	fn set_version_keeps_comments_and_order() {
+		let mut catalog = VersionCatalog::parse(CONTENT);
+		catalog.set_version("minecraft", "1.21.1");
+
+		assert_eq!(
+			catalog.to_string(),
+			CONTENT.replace("minecraft = \"1.21\"", "minecraft = \"1.21.1\"")
+		);
+	}
+
+	#[test]
+	fn new_entries_go_after_the_last_entry_of_their_section() {
+		let mut catalog = VersionCatalog::parse(CONTENT);
+		catalog.set_version("sodium", "mc1.21-0.5.11");
+		catalog.add_library("sodium", "maven.modrinth:sodium", "sodium");
+
+		assert_eq!(
+			catalog.to_string(),
+			CONTENT
+				.replace(
+					"loom = \"1.7-SNAPSHOT\"\n",
+					"loom = \"1.7-SNAPSHOT\"\nsodium = \"mc1.21-0.5.11\"\n"
+				)
+				.replace(
+					"version.ref = \"minecraft\" }\n",
+					"version.ref = \"minecraft\" }\n\
+					 sodium = { module = \"maven.modrinth:sodium\", version.ref = \"sodium\" }\n"
+				)
+		);
+	}
+
+	#[test]
+	fn missing_sections_are_created_at_the_end() {
+		let mut catalog = VersionCatalog::parse("[versions]\nminecraft = \"1.21\"\n");
+		catalog.add_plugin(
+			"mod-publish",
+			"me.modmuss50.mod-publish-plugin",
+			"mod-publish",
+		);
+
+		assert_eq!(
+			catalog.to_string(),
+			"[versions]\nminecraft = \"1.21\"\n\n[plugins]\n\
+			 mod-publish = { id = \"me.modmuss50.mod-publish-plugin\", version.ref = \"mod-publish\" }\n"
+		);
+	}
+
+	#[test]
+	fn accessors_follow_gradle_naming() {
+		assert_eq!(accessor("fabric-api"), "libs.fabric.api");
+		assert_eq!(accessor("fabric_loader"), "libs.fabric.loader");
+		assert_eq!(plugin_accessor("mod-publish"), "libs.plugins.mod.publish");
+	}
+
+	#[test]
+	fn aliases_are_valid_for_gradle() {
+		assert_eq!(alias("sodium"), "sodium");
+		assert_eq!(alias("fabric-language-kotlin"), "fabric-language-kotlin");
+		assert_eq!(alias("cloth.config"), "cloth-config");
+		assert_eq!(alias("Mod_Menu"), "mod-menu");
+		assert_eq!(alias("3d-skin-layers"), "mod-3d-skin-layers");
+		assert_eq!(alias("a--b."), "a-b");
+	}
+}
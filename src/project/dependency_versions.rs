@@ -0,0 +1,93 @@
+use super::{catalog::VersionCatalog, properties::GradleProperties, Project};
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+/// `gradle.properties` keys and the version catalog aliases holding the same version.
+const CATALOG_ALIASES: [(&str, &str); 5] = [
+	("minecraft_version", "minecraft"),
+	("yarn_mappings", "yarn"),
+	("fabric_loader_version", "fabric-loader"),
+	("fabric_api_version", "fabric-api"),
+	("loom", "loom"),
+];
+
+/// The dependency versions of a project, kept either in `gradle.properties` or
+/// in a version catalog. Keys are always the `gradle.properties` names.
+#[derive(Debug)]
+pub enum DependencyVersions {
+	Properties {
+		path: PathBuf,
+		properties: GradleProperties,
+	},
+	Catalog {
+		path: PathBuf,
+		catalog: VersionCatalog,
+	},
+}
+
+impl DependencyVersions {
+	pub fn load(project: &Project) -> Result<Self> {
+		let catalog_path = project.version_catalog_path();
+		if catalog_path.is_file() {
+			let catalog = VersionCatalog::load(&catalog_path)?;
+			if catalog.version("minecraft").is_some() {
+				return Ok(Self::Catalog {
+					path: catalog_path,
+					catalog,
+				});
+			}
+		}
+
+		let path = project.properties_path();
+		Ok(Self::Properties {
+			properties: GradleProperties::load(&path)?,
+			path,
+		})
+	}
+
+	pub fn is_catalog(&self) -> bool {
+		matches!(self, Self::Catalog { .. })
+	}
+
+	/// The file the versions are kept in, relative to the project directory.
+	pub fn file_name(&self) -> &'static str {
+		match self {
+			Self::Properties { .. } => "gradle.properties",
+			Self::Catalog { .. } => "gradle/libs.versions.toml",
+		}
+	}
+
+	pub fn get(&self, key: &str) -> Option<&str> {
+		match self {
+			Self::Properties { properties, .. } => properties.get(key),
+			Self::Catalog { catalog, .. } => catalog.version(catalog_alias(key)),
+		}
+	}
+
+	pub fn require(&self, key: &str) -> Result<String> {
+		self.get(key)
+			.map(String::from)
+			.with_context(|| format!("{} doesn't define {}", self.file_name(), key))
+	}
+
+	pub fn set(&mut self, key: &str, value: &str) {
+		match self {
+			Self::Properties { properties, .. } => properties.set(key, value),
+			Self::Catalog { catalog, .. } => catalog.set_version(catalog_alias(key), value),
+		}
+	}
+
+	pub fn save(&self) -> Result<()> {
+		match self {
+			Self::Properties { path, properties } => properties.save(path),
+			Self::Catalog { path, catalog } => catalog.save(path),
+		}
+	}
+}
+
+fn catalog_alias(key: &str) -> &str {
+	CATALOG_ALIASES
+		.iter()
+		.find(|(property, _)| *property == key)
+		.map_or(key, |(_, alias)| *alias)
+}
@@ -0,0 +1,115 @@
+pub mod build_script;
+pub mod catalog;
+pub mod dependency_versions;
+pub mod mod_json;
+pub mod properties;
+
+use crate::input::gradle_dsl::GradleDsl;
+use crate::manifest::Manifest;
+use anyhow::{bail, Context, Result};
+use dependency_versions::DependencyVersions;
+use dunce::canonicalize;
+use std::path::{Path, PathBuf};
+
+/// The identity of a mod, read back from its `fabric.mod.json` and `gradle.properties`.
+#[derive(Debug)]
+pub struct ModInfo {
+	pub mod_id: String,
+	pub package: String,
+	pub class_name: String,
+	pub minecraft_version: String,
+}
+
+/// An existing mod project on disk, as opposed to one that is about to be scaffolded.
+#[derive(Debug)]
+pub struct Project {
+	pub dir: PathBuf,
+	pub manifest: Option<Manifest>,
+}
+
+impl Project {
+	pub fn open(path: &Path) -> Result<Self> {
+		let dir = canonicalize(path)
+			.with_context(|| format!("Project directory '{}' does not exist", path.display()))?;
+
+		if !dir.join("gradle.properties").is_file() {
+			bail!(
+				"'{}' doesn't look like a mod project: gradle.properties is missing",
+				dir.display()
+			);
+		}
+
+		let manifest = Manifest::load(&dir)?;
+
+		Ok(Self { dir, manifest })
+	}
+
+	pub fn gradle_dsl(&self) -> GradleDsl {
+		if self.dir.join(GradleDsl::Kotlin.build_file_name()).is_file() {
+			GradleDsl::Kotlin
+		} else {
+			GradleDsl::Groovy
+		}
+	}
+
+	pub fn properties_path(&self) -> PathBuf {
+		self.dir.join("gradle.properties")
+	}
+
+	pub fn build_script_path(&self) -> PathBuf {
+		self.dir.join(self.gradle_dsl().build_file_name())
+	}
+
+	pub fn version_catalog_path(&self) -> PathBuf {
+		self.dir.join("gradle").join("libs.versions.toml")
+	}
+
+	pub fn wrapper_properties_path(&self) -> PathBuf {
+		self.dir
+			.join("gradle")
+			.join("wrapper")
+			.join("gradle-wrapper.properties")
+	}
+
+	pub fn mod_info(&self) -> Result<ModInfo> {
+		let mod_json = mod_json::load(&self.mod_json_path())?;
+		let mod_id = mod_json["id"]
+			.as_str()
+			.context("fabric.mod.json doesn't define an id")?;
+
+		let main_entrypoint = mod_json["entrypoints"]["main"]
+			.as_array()
+			.and_then(|entrypoints| entrypoints.first())
+			.and_then(|entry| entry.as_str().or_else(|| entry["value"].as_str()))
+			.context("fabric.mod.json doesn't define a main entrypoint")?;
+		let main_class = main_entrypoint
+			.split("::")
+			.next()
+			.unwrap_or(main_entrypoint);
+		let (package, class_name) = main_class
+			.rsplit_once('.')
+			.with_context(|| format!("Main entrypoint '{}' has no package", main_class))?;
+
+		let minecraft_version = DependencyVersions::load(self)?.require("minecraft_version")?;
+
+		Ok(ModInfo {
+			mod_id: mod_id.to_string(),
+			package: package.to_string(),
+			class_name: class_name.to_string(),
+			minecraft_version,
+		})
+	}
+
+	/// The root of a Gradle source set, such as `src/main` or `src/test`.
+	pub fn source_set_dir(&self, source_set: &str) -> PathBuf {
+		self.dir.join("src").join(source_set)
+	}
+
+	pub fn resources_dir(&self) -> PathBuf {
+		self.source_set_dir("main").join("resources")
+	}
+
+	pub fn mod_json_path(&self) -> PathBuf {
+		self.resources_dir().join("fabric.mod.json")
+	}
+}
@@ -0,0 +1,212 @@
+use crate::input::contact::Contact;
+use anyhow::{Context, Result};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::{fs, path::Path};
+
+/// Dependency mod ids mapped to the version range they must match.
+pub type Dependencies = IndexMap<String, VersionRange>;
+
+/// A `fabric.mod.json` file (schema version 1).
+///
+/// Used to write the file for new projects. Existing files are edited as plain
+/// JSON instead, so fields this model doesn't know about survive.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModJson {
+	pub schema_version: u8,
+	pub id: String,
+	pub version: String,
+	pub name: String,
+	#[serde(default, skip_serializing_if = "String::is_empty")]
+	pub description: String,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub authors: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub contributors: Vec<String>,
+	#[serde(default, skip_serializing_if = "Contact::is_empty")]
+	pub contact: Contact,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub license: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub icon: Option<String>,
+	#[serde(default)]
+	pub environment: Environment,
+	#[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+	pub entrypoints: IndexMap<String, Vec<String>>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub mixins: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub access_widener: Option<String>,
+	#[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+	pub depends: Dependencies,
+	#[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+	pub recommends: Dependencies,
+	#[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+	pub suggests: Dependencies,
+	#[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+	pub breaks: Dependencies,
+	#[serde(default, skip_serializing_if = "Map::is_empty")]
+	pub custom: Map<String, Value>,
+}
+
+impl ModJson {
+	pub const SCHEMA_VERSION: u8 = 1;
+
+	/// A mod with only the required fields set.
+	pub fn new(id: &str, version: &str, name: &str) -> Self {
+		Self {
+			schema_version: Self::SCHEMA_VERSION,
+			id: id.to_string(),
+			version: version.to_string(),
+			name: name.to_string(),
+			description: String::new(),
+			authors: Vec::new(),
+			contributors: Vec::new(),
+			contact: Contact::default(),
+			license: None,
+			icon: None,
+			environment: Environment::Any,
+			entrypoints: IndexMap::new(),
+			mixins: Vec::new(),
+			access_widener: None,
+			depends: IndexMap::new(),
+			recommends: IndexMap::new(),
+			suggests: IndexMap::new(),
+			breaks: IndexMap::new(),
+			custom: Map::new(),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Environment {
+	#[default]
+	#[serde(rename = "*")]
+	Any,
+	Client,
+	Server,
+}
+
+/// A single version range, or a list of ranges of which any may match.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum VersionRange {
+	One(String),
+	Any(Vec<String>),
+}
+
+impl From<&str> for VersionRange {
+	fn from(range: &str) -> Self {
+		Self::One(range.to_string())
+	}
+}
+
+impl From<String> for VersionRange {
+	fn from(range: String) -> Self {
+		Self::One(range)
+	}
+}
+
+pub fn load(path: &Path) -> Result<Value> {
+	let content =
+		fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+	serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+pub fn save(path: &Path, value: &impl Serialize) -> Result<()> {
+	let content = serde_json::to_string_pretty(value)
+		.with_context(|| format!("Failed to serialize {}", path.display()))?;
+
+	fs::write(path, content + "\n").with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// The mixin config file names listed under `mixins`, in either their plain
+/// string or `{ "config": ... }` form.
+pub fn mixin_configs(mod_json: &Value) -> Vec<String> {
+	mod_json["mixins"]
+		.as_array()
+		.into_iter()
+		.flatten()
+		.filter_map(|entry| entry.as_str().or_else(|| entry["config"].as_str()))
+		.map(String::from)
+		.collect()
+}
+
+pub fn has_entrypoint(mod_json: &Value, kind: &str) -> bool {
+	mod_json["entrypoints"][kind]
+		.as_array()
+		.is_some_and(|entrypoints| !entrypoints.is_empty())
+}
+
+pub fn add_entrypoint(mod_json: &mut Value, kind: &str, class: &str) -> Result<()> {
+	let entrypoints = child_object(mod_json, "entrypoints")?;
+	let list = entrypoints.entry(kind).or_insert_with(|| json!([]));
+	list.as_array_mut()
+		.with_context(|| format!("`entrypoints.{}` in fabric.mod.json must be an array", kind))?
+		.push(json!(class));
+
+	Ok(())
+}
+
+pub fn add_mixin_config(mod_json: &mut Value, config: &str) -> Result<()> {
+	if mixin_configs(mod_json).iter().any(|c| c == config) {
+		return Ok(());
+	}
+
+	object_mut(mod_json)?
+		.entry("mixins")
+		.or_insert_with(|| json!([]))
+		.as_array_mut()
+		.context("`mixins` in fabric.mod.json must be an array")?
+		.push(json!(config));
+
+	Ok(())
+}
+
+pub fn add_dependency(mod_json: &mut Value, mod_id: &str, range: &str) -> Result<()> {
+	child_object(mod_json, "depends")?.insert(mod_id.to_string(), json!(range));
+
+	Ok(())
+}
+
+pub fn object_mut(mod_json: &mut Value) -> Result<&mut Map<String, Value>> {
+	mod_json
+		.as_object_mut()
+		.context("fabric.mod.json must contain a JSON object")
+}
+
+/// Returns the object stored under `key`, inserting an empty one if it's missing.
+pub fn child_object<'a>(mod_json: &'a mut Value, key: &str) -> Result<&'a mut Map<String, Value>> {
+	object_mut(mod_json)?
+		.entry(key)
+		.or_insert_with(|| json!({}))
+		.as_object_mut()
+		.with_context(|| format!("`{}` in fabric.mod.json must be an object", key))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn descriptions_survive_a_round_trip() {
+		let description = "Says \"hi\",\ncosts $5 and keeps ${version} in C:\\mods";
+		let mod_json = ModJson {
+			description: description.to_string(),
+			..ModJson::new("example", "1.0.0", "Example")
+		};
+
+		let content = serde_json::to_string_pretty(&mod_json).unwrap();
+		assert!(content.contains(
+			r#""description": "Says \"hi\",\ncosts $5 and keeps ${version} in C:\\mods""#
+		));
+
+		let parsed: ModJson = serde_json::from_str(&content).unwrap();
+		assert_eq!(parsed.description, description);
+		assert_eq!(parsed.version, "1.0.0");
+	}
+}
@@ -0,0 +1,143 @@
+use anyhow::{Context, Result};
+use std::{fs, path::Path};
+
+/// A `gradle.properties` file that can be edited in place without losing
+/// comments, blank lines or the order of its entries.
+#[derive(Debug)]
+pub struct GradleProperties {
+	lines: Vec<String>,
+}
+
+impl GradleProperties {
+	pub fn load(path: &Path) -> Result<Self> {
+		let content = fs::read_to_string(path)
+			.with_context(|| format!("Failed to read {}", path.display()))?;
+
+		Ok(Self::parse(&content))
+	}
+
+	pub fn parse(content: &str) -> Self {
+		Self {
+			lines: content.lines().map(String::from).collect(),
+		}
+	}
+
+	pub fn save(&self, path: &Path) -> Result<()> {
+		fs::write(path, self.to_string())
+			.with_context(|| format!("Failed to write {}", path.display()))
+	}
+
+	pub fn get(&self, key: &str) -> Option<&str> {
+		self.lines
+			.iter()
+			.filter_map(|line| split_entry(line))
+			.find(|(k, _, _)| *k == key)
+			.map(|(_, _, value)| value)
+	}
+
+	/// Replaces the value of `key`, or appends a new entry if it doesn't exist yet.
+	pub fn set(&mut self, key: &str, value: &str) {
+		for line in &mut self.lines {
+			if let Some((k, value_start, _)) = split_entry(line) {
+				if k == key {
+					let prefix = line[..value_start].to_string();
+					*line = format!("{}{}", prefix, value);
+					return;
+				}
+			}
+		}
+
+		self.lines.push(format!("{}={}", key, value));
+	}
+}
+
+impl std::fmt::Display for GradleProperties {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for line in &self.lines {
+			writeln!(f, "{}", line)?;
+		}
+		Ok(())
+	}
+}
+
+/// Splits a property line into its key, the byte offset its value starts at
+/// and the value itself. Comments and blank lines yield `None`.
+fn split_entry(line: &str) -> Option<(&str, usize, &str)> {
+	let trimmed = line.trim_start();
+	if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('!') {
+		return None;
+	}
+
+	let separator = line.find(['=', ':'])?;
+	let key = line[..separator].trim();
+	let rest = &line[separator + 1..];
+	let value_start = separator + 1 + (rest.len() - rest.trim_start().len());
+
+	Some((key, value_start, line[value_start..].trim_end()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const CONTENT: &str = "\
+# Done to increase the memory available to gradle.
+org.gradle.jvmargs=-Xmx1G
+
+# Fabric Properties
+minecraft_version = 1.21
+yarn_mappings : 1.21+build.9
+! legacy comment
+distributionUrl=https\\://services.gradle.org/distributions/gradle-8.9-bin.zip
+";
+
+	#[test]
+	fn reads_values_with_any_separator() {
+		let properties = GradleProperties::parse(CONTENT);
+
+		assert_eq!(properties.get("org.gradle.jvmargs"), Some("-Xmx1G"));
+		assert_eq!(properties.get("minecraft_version"), Some("1.21"));
+		assert_eq!(properties.get("yarn_mappings"), Some("1.21+build.9"));
+		assert_eq!(
+			properties.get("distributionUrl"),
+			Some("https\\://services.gradle.org/distributions/gradle-8.9-bin.zip")
+		);
+		assert_eq!(properties.get("legacy"), None);
+		assert_eq!(properties.get("missing"), None);
+	}
+
+	#[test]
+	fn set_keeps_comments_order_and_separators() {
+		let mut properties = GradleProperties::parse(CONTENT);
+		properties.set("minecraft_version", "1.21.1");
+		properties.set("yarn_mappings", "1.21.1+build.3");
+
+		assert_eq!(
+			properties.to_string(),
+			CONTENT
+				.replace("= 1.21\n", "= 1.21.1\n")
+				.replace(": 1.21+build.9", ": 1.21.1+build.3")
+		);
+	}
+
+	#[test]
+	fn set_keeps_escaped_values_of_other_keys() {
+		let mut properties = GradleProperties::parse(CONTENT);
+		properties.set("org.gradle.jvmargs", "-Xmx4G");
+
+		assert!(properties
+			.to_string()
+			.contains("distributionUrl=https\\://services.gradle.org/"));
+	}
+
+	#[test]
+	fn set_appends_missing_keys() {
+		let mut properties = GradleProperties::parse(CONTENT);
+		properties.set("fabric_api_version", "0.102.0+1.21");
+
+		assert_eq!(
+			properties.to_string(),
+			format!("{}fabric_api_version=0.102.0+1.21\n", CONTENT)
+		);
+	}
+}
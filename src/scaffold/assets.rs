@@ -1,6 +1,8 @@
-use crate::input::UserInput;
-use crate::utils::{create_directory, sanitize_mod_id};
+use super::icon;
+use crate::input::{icon::validate_icon, UserInput};
+use crate::utils::create_directory;
 use anyhow::{Context, Result};
+use serde_json::json;
 use std::{fs, path::Path};
 
 pub fn create(project_dir: &Path, input: &UserInput) -> Result<()> {
@@ -11,18 +13,44 @@ pub fn create(project_dir: &Path, input: &UserInput) -> Result<()> {
 		.join("assets");
 	create_directory(&assets_dir)?;
 
-	let mod_id = sanitize_mod_id(&input.location.mod_name);
-	let mod_assets_dir = assets_dir.join(&mod_id);
+	let mod_id = input.mod_id();
+	let mod_assets_dir = assets_dir.join(mod_id);
 	create_directory(&mod_assets_dir)?;
 
-	let icon_src = Path::new(env!("CARGO_MANIFEST_DIR"))
-		.join("templates")
-		.join("common")
-		.join("icon.png");
 	let icon_dst = mod_assets_dir.join("icon.png");
 
-	fs::copy(&icon_src, &icon_dst)
-		.with_context(|| format!("Failed to copy icon.png to {}", icon_dst.display()))?;
+	match &input.icon {
+		Some(icon_src) => {
+			validate_icon(icon_src)?;
+			fs::copy(icon_src, &icon_dst)
+				.with_context(|| format!("Failed to copy icon.png to {}", icon_dst.display()))?;
+		}
+		None => icon::create(&icon_dst, mod_id, &input.location.mod_name)?,
+	}
+
+	create_lang_files(&mod_assets_dir, mod_id, input)
+}
+
+/// Writes `en_us.json` with starter keys, and a copy of it for every extra
+/// locale so translators can see what needs translating.
+fn create_lang_files(mod_assets_dir: &Path, mod_id: &str, input: &UserInput) -> Result<()> {
+	let lang_dir = mod_assets_dir.join("lang");
+	create_directory(&lang_dir)?;
+
+	let translations = json!({
+		format!("modmenu.nameTranslation.{}", mod_id): input.location.mod_name,
+		format!("modmenu.descriptionTranslation.{}", mod_id): input.description,
+		format!("item.{}.example_item", mod_id): "Example Item",
+		format!("itemGroup.{}.example_group", mod_id): input.location.mod_name,
+	});
+
+	let locales = std::iter::once("en_us").chain(input.locales.iter().map(String::as_str));
+	let content = serde_json::to_string_pretty(&translations)? + "\n";
+	for locale in locales {
+		let path = lang_dir.join(format!("{}.json", locale));
+		fs::write(&path, &content)
+			.with_context(|| format!("Failed to write {}", path.display()))?;
+	}
 
 	Ok(())
 }
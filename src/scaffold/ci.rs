@@ -0,0 +1,30 @@
+use crate::input::{ci_provider::CiProvider, UserInput};
+use crate::utils::{create_directory, get_java_version};
+use anyhow::{Context, Result};
+use std::{fs, path::Path};
+
+pub fn create(project_dir: &Path, input: &UserInput) -> Result<()> {
+	let (template, destination) = match input.ci {
+		CiProvider::None => return Ok(()),
+		CiProvider::GitHub => ("ci/github.yml", ".github/workflows/build.yml"),
+		CiProvider::GitLab => ("ci/gitlab.yml", ".gitlab-ci.yml"),
+		CiProvider::Forgejo => ("ci/forgejo.yml", ".forgejo/workflows/build.yml"),
+	};
+
+	let content = super::read_template(template)
+		.with_context(|| format!("Failed to read {} template", input.ci))?
+		.replace(
+			"${{ java_version }}",
+			get_java_version(&input.minecraft_version)?,
+		);
+
+	let target_path = project_dir.join(destination);
+	if let Some(parent) = target_path.parent() {
+		create_directory(parent)?;
+	}
+
+	fs::write(&target_path, content)
+		.with_context(|| format!("Failed to write CI pipeline to {}", target_path.display()))?;
+
+	Ok(())
+}
@@ -5,6 +5,7 @@ pub fn copy(project_dir: &Path) -> Result<()> {
 	let common_files = [
 		(".gitignore.template", ".gitignore"),
 		(".gitattributes.template", ".gitattributes"),
+		(".editorconfig.template", ".editorconfig"),
 	];
 
 	for (src, dst) in &common_files {
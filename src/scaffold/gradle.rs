@@ -1,5 +1,9 @@
 use crate::input::{gradle_dsl::GradleDsl, UserInput};
-use crate::utils::{create_directory, get_java_version};
+use crate::project::{
+	build_script::BuildScript,
+	catalog::{self, VersionCatalog},
+};
+use crate::utils::{create_directory, get_gradle_version, get_java_version, get_loom_version};
 use anyhow::{Context, Result};
 use std::{fs, path::Path};
 
@@ -11,7 +15,11 @@ pub fn create(project_dir: &Path, input: &UserInput) -> Result<()> {
 
 	create_build_gradle(project_dir, input, dsl_subdir)?;
 	create_gradle_properties(project_dir, input)?;
-	copy_wrapper_files(project_dir)?;
+	if input.version_catalog {
+		create_version_catalog(project_dir, input)?;
+	}
+	configure_publishing(project_dir, input)?;
+	copy_wrapper_files(project_dir, input)?;
 	copy_settings_file(project_dir, dsl_subdir)?;
 
 	Ok(())
@@ -32,25 +40,160 @@ fn create_build_gradle(project_dir: &Path, input: &UserInput, dsl_subdir: &str)
 		.context("Failed to read build.gradle template")?;
 
 	let java_version = get_java_version(&input.minecraft_version)?;
-	let processed_build_content = build_content.replace("${{ java_version }}", java_version);
+	let mut processed_build_content = build_content.replace("${{ java_version }}", java_version);
+	for (placeholder, notation) in dependency_notations(input)? {
+		processed_build_content =
+			processed_build_content.replace(&format!("${{{{ {} }}}}", placeholder), &notation);
+	}
+
+	fs::write(
+		project_dir.join(input.gradle_dsl.build_file_name()),
+		processed_build_content,
+	)
+	.context("Failed to write build.gradle file")?;
+
+	Ok(())
+}
+
+/// How the build script refers to Loom and the dependencies: through the
+/// version catalog, or through the versions in `gradle.properties`.
+fn dependency_notations(input: &UserInput) -> Result<[(&'static str, String); 5]> {
+	if input.version_catalog {
+		return Ok([
+			("loom_plugin", "alias(libs.plugins.loom)".to_string()),
+			("minecraft_dependency", "libs.minecraft".to_string()),
+			(
+				"mappings_dependency",
+				"variantOf(libs.yarn) { classifier(\"v2\") }".to_string(),
+			),
+			("loader_dependency", "libs.fabric.loader".to_string()),
+			("fabric_api_dependency", "libs.fabric.api".to_string()),
+		]);
+	}
 
-	let build_file_name = match input.gradle_dsl {
-		GradleDsl::Groovy => "build.gradle",
-		GradleDsl::Kotlin => "build.gradle.kts",
+	let loom_version = get_loom_version(&input.minecraft_version)?;
+	let (loom_plugin, property): (String, fn(&str) -> String) = match input.gradle_dsl {
+		GradleDsl::Groovy => (
+			format!("id 'fabric-loom' version '{}'", loom_version),
+			|name| format!("${{project.{}}}", name),
+		),
+		GradleDsl::Kotlin => (
+			format!("id(\"fabric-loom\") version \"{}\"", loom_version),
+			|name| format!("${{project.property(\"{}\")}}", name),
+		),
 	};
 
-	fs::write(project_dir.join(build_file_name), processed_build_content)
-		.context("Failed to write build.gradle file")?;
+	Ok([
+		("loom_plugin", loom_plugin),
+		(
+			"minecraft_dependency",
+			format!("\"com.mojang:minecraft:{}\"", property("minecraft_version")),
+		),
+		(
+			"mappings_dependency",
+			format!("\"net.fabricmc:yarn:{}:v2\"", property("yarn_mappings")),
+		),
+		(
+			"loader_dependency",
+			format!(
+				"\"net.fabricmc:fabric-loader:{}\"",
+				property("fabric_loader_version")
+			),
+		),
+		(
+			"fabric_api_dependency",
+			format!(
+				"\"net.fabricmc.fabric-api:fabric-api:{}\"",
+				property("fabric_api_version")
+			),
+		),
+	])
+}
 
-	Ok(())
+const PUBLISH_PLUGIN: &str = "me.modmuss50.mod-publish-plugin";
+const PUBLISH_PLUGIN_VERSION: &str = "0.7.4";
+const PUBLISH_PLUGIN_ALIAS: &str = "mod-publish";
+
+fn configure_publishing(project_dir: &Path, input: &UserInput) -> Result<()> {
+	if !input.publishing.is_enabled() {
+		return Ok(());
+	}
+
+	let platforms = [
+		("publishing_modrinth", &input.publishing.modrinth),
+		("publishing_curseforge", &input.publishing.curseforge),
+	];
+
+	let minecraft_versions = if input.publishing.minecraft_versions.is_empty() {
+		format!("\"{}\"", input.minecraft_version)
+	} else {
+		input
+			.publishing
+			.minecraft_versions
+			.iter()
+			.map(|version| format!("\"{}\"", version))
+			.collect::<Vec<_>>()
+			.join(", ")
+	};
+
+	let mut platform_blocks = Vec::new();
+	for (snippet, project_id) in platforms {
+		if let Some(project_id) = project_id {
+			let block = super::read_snippet(input.gradle_dsl, snippet)?
+				.replace("${{ project_id }}", project_id)
+				.replace("${{ minecraft_versions }}", &minecraft_versions);
+			platform_blocks.push(block);
+		}
+	}
+
+	let publish_mods = super::read_snippet(input.gradle_dsl, "publishing")
+		.context("Failed to read publishing template")?
+		.replace("${{ platforms }}", &platform_blocks.join("\n\n"));
+
+	let plugin = if input.version_catalog {
+		let catalog_path = project_dir.join("gradle").join("libs.versions.toml");
+		let mut catalog = VersionCatalog::load(&catalog_path)?;
+		catalog.set_version(PUBLISH_PLUGIN_ALIAS, PUBLISH_PLUGIN_VERSION);
+		catalog.add_plugin(PUBLISH_PLUGIN_ALIAS, PUBLISH_PLUGIN, PUBLISH_PLUGIN_ALIAS);
+		catalog.save(&catalog_path)?;
+
+		format!("alias({})", catalog::plugin_accessor(PUBLISH_PLUGIN_ALIAS))
+	} else {
+		match input.gradle_dsl {
+			GradleDsl::Groovy => format!(
+				"id '{}' version '{}'",
+				PUBLISH_PLUGIN, PUBLISH_PLUGIN_VERSION
+			),
+			GradleDsl::Kotlin => format!(
+				"id(\"{}\") version \"{}\"",
+				PUBLISH_PLUGIN, PUBLISH_PLUGIN_VERSION
+			),
+		}
+	};
+
+	let mut build_script =
+		BuildScript::load(&project_dir.join(input.gradle_dsl.build_file_name()))?;
+	build_script.insert_into_block("plugins", &plugin);
+	build_script.append(&publish_mods);
+	build_script.save()
 }
 
 fn create_gradle_properties(project_dir: &Path, input: &UserInput) -> Result<()> {
-	let gradle_properties_content = super::read_template("gradle/gradle.properties")
-		.context("Failed to read gradle.properties template")?;
+	// With a version catalog, dependency versions live in `libs.versions.toml` instead.
+	let template = if input.version_catalog {
+		"gradle/gradle.catalog.properties"
+	} else {
+		"gradle/gradle.properties"
+	};
+	let gradle_properties_content =
+		super::read_template(template).context("Failed to read gradle.properties template")?;
 
 	let processed_gradle_properties = gradle_properties_content
-		.replace("${{ base_name }}", &input.location.mod_name.to_lowercase())
+		.replace(
+			"${{ gradle_settings }}",
+			&input.gradle_settings.properties(),
+		)
+		.replace("${{ base_name }}", input.mod_id())
 		.replace("${{ maven_group }}", &input.maven_group)
 		.replace("${{ mod_name }}", &input.location.mod_name)
 		.replace("${{ mod_version }}", &input.version)
@@ -68,19 +211,49 @@ fn create_gradle_properties(project_dir: &Path, input: &UserInput) -> Result<()>
 	Ok(())
 }
 
-fn copy_wrapper_files(project_dir: &Path) -> Result<()> {
+fn create_version_catalog(project_dir: &Path, input: &UserInput) -> Result<()> {
+	let catalog = super::read_template("gradle/libs.versions.toml")
+		.context("Failed to read libs.versions.toml template")?
+		.replace("${{ minecraft_version }}", &input.minecraft_version)
+		.replace("${{ yarn_mappings }}", &input.yarn_version)
+		.replace("${{ fabric_loader_version }}", &input.fabric_loader_version)
+		.replace("${{ fabric_api_version }}", &input.fabric_api_version)
+		.replace(
+			"${{ loom_version }}",
+			get_loom_version(&input.minecraft_version)?,
+		);
+
+	let gradle_dir = project_dir.join("gradle");
+	create_directory(&gradle_dir)?;
+
+	fs::write(gradle_dir.join("libs.versions.toml"), catalog)
+		.context("Failed to write libs.versions.toml file")?;
+
+	Ok(())
+}
+
+fn copy_wrapper_files(project_dir: &Path, input: &UserInput) -> Result<()> {
 	let wrapper_dir = project_dir.join("gradle").join("wrapper");
 	create_directory(&wrapper_dir)?;
 
+	let wrapper_properties = super::read_template("gradle/wrapper/gradle-wrapper.properties")
+		.context("Failed to read gradle-wrapper.properties template")?
+		.replace(
+			"${{ gradle_version }}",
+			get_gradle_version(&input.minecraft_version)?,
+		);
+
+	fs::write(
+		wrapper_dir.join("gradle-wrapper.properties"),
+		wrapper_properties,
+	)
+	.context("Failed to write gradle-wrapper.properties file")?;
+
 	let wrapper_files = [
 		(
 			"gradle/wrapper/gradle-wrapper.jar",
 			"gradle/wrapper/gradle-wrapper.jar",
 		),
-		(
-			"gradle/wrapper/gradle-wrapper.properties",
-			"gradle/wrapper/gradle-wrapper.properties",
-		),
 		("gradle/gradlew", "gradlew"),
 		("gradle/gradlew.bat", "gradlew.bat"),
 	];
@@ -0,0 +1,175 @@
+use anyhow::{Context, Result};
+use std::{fs::File, io::BufWriter, path::Path};
+
+const SIZE: u32 = 128;
+/// The background pattern is a grid of this many cells per side, mirrored left to right.
+const GRID: u32 = 8;
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+const GLYPH_SCALE: u32 = 8;
+
+/// Writes a placeholder icon: an identicon derived from the mod ID, with the
+/// initials of the mod name on top.
+pub fn create(path: &Path, mod_id: &str, mod_name: &str) -> Result<()> {
+	let pixels = render(mod_id, mod_name);
+
+	let file =
+		File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+	let mut encoder = png::Encoder::new(BufWriter::new(file), SIZE, SIZE);
+	encoder.set_color(png::ColorType::Rgb);
+	encoder.set_depth(png::BitDepth::Eight);
+
+	encoder
+		.write_header()
+		.and_then(|mut writer| writer.write_image_data(&pixels))
+		.with_context(|| format!("Failed to write icon to {}", path.display()))
+}
+
+fn render(mod_id: &str, mod_name: &str) -> Vec<u8> {
+	let hash = fnv1a(mod_id);
+	let hue = ((hash >> 32) % 360) as f32;
+
+	let background = hsl_to_rgb(hue, 0.55, 0.45);
+	let pattern = hsl_to_rgb(hue, 0.55, 0.38);
+	let shadow = hsl_to_rgb(hue, 0.55, 0.22);
+	let text = [255, 255, 255];
+
+	let mut canvas = vec![background; (SIZE * SIZE) as usize];
+
+	let cell = SIZE / GRID;
+	for row in 0..GRID {
+		for col in 0..GRID / 2 {
+			if hash >> (row * GRID / 2 + col) & 1 == 0 {
+				continue;
+			}
+			for mirrored in [col, GRID - 1 - col] {
+				fill(&mut canvas, mirrored * cell, row * cell, cell, pattern);
+			}
+		}
+	}
+
+	let glyphs: Vec<_> = initials(mod_name).chars().filter_map(glyph).collect();
+	if !glyphs.is_empty() {
+		let columns = glyphs.len() as u32 * (GLYPH_WIDTH + 1) - 1;
+		let left = (SIZE - columns * GLYPH_SCALE) / 2;
+		let top = (SIZE - GLYPH_HEIGHT * GLYPH_SCALE) / 2;
+		let offset = GLYPH_SCALE / 2;
+
+		for (x, y, color) in [(left + offset, top + offset, shadow), (left, top, text)] {
+			for (i, rows) in glyphs.iter().enumerate() {
+				let glyph_left = x + i as u32 * (GLYPH_WIDTH + 1) * GLYPH_SCALE;
+				draw_glyph(&mut canvas, rows, glyph_left, y, color);
+			}
+		}
+	}
+
+	canvas.concat()
+}
+
+fn fill(canvas: &mut [[u8; 3]], left: u32, top: u32, size: u32, color: [u8; 3]) {
+	for y in top..(top + size).min(SIZE) {
+		for x in left..(left + size).min(SIZE) {
+			canvas[(y * SIZE + x) as usize] = color;
+		}
+	}
+}
+
+fn draw_glyph(canvas: &mut [[u8; 3]], rows: &[u8; 7], left: u32, top: u32, color: [u8; 3]) {
+	for (row, bits) in rows.iter().enumerate() {
+		for col in 0..GLYPH_WIDTH {
+			if bits >> (GLYPH_WIDTH - 1 - col) & 1 == 1 {
+				let x = left + col * GLYPH_SCALE;
+				let y = top + row as u32 * GLYPH_SCALE;
+				fill(canvas, x, y, GLYPH_SCALE, color);
+			}
+		}
+	}
+}
+
+/// Up to two initials: the first letter of each word, where words are split on
+/// separators and at lowercase-to-uppercase transitions (`MyCoolMod` gives `MC`).
+fn initials(name: &str) -> String {
+	let mut initials = String::new();
+	let mut previous: Option<char> = None;
+
+	for c in name.chars() {
+		let starts_word = match previous {
+			None => true,
+			Some(p) => !p.is_ascii_alphanumeric() || (p.is_lowercase() && c.is_uppercase()),
+		};
+		if starts_word && c.is_ascii_alphanumeric() {
+			initials.push(c.to_ascii_uppercase());
+		}
+		previous = Some(c);
+	}
+
+	initials.chars().take(2).collect()
+}
+
+/// A 5x7 bitmap of `c`, one byte per row with the leftmost pixel in bit 4.
+fn glyph(c: char) -> Option<[u8; 7]> {
+	Some(match c {
+		'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+		'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+		'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+		'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+		'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+		'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+		'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+		'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+		'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+		'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+		'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+		'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+		'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+		'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+		'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+		'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+		'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+		'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+		'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+		'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+		'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+		'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+		'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+		'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+		'Y' => [0x11, 0x11, 0x0A, 0x04, 0x04, 0x04, 0x04],
+		'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+		'0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+		'1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+		'2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+		'3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+		'4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+		'5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+		'6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+		'7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+		'8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+		'9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+		_ => return None,
+	})
+}
+
+/// FNV-1a, used instead of `DefaultHasher` so icons stay the same across Rust releases.
+fn fnv1a(value: &str) -> u64 {
+	value.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+		(hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+	})
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> [u8; 3] {
+	let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+	let sector = hue / 60.0;
+	let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+	let (r, g, b) = match sector as u32 {
+		0 => (chroma, x, 0.0),
+		1 => (x, chroma, 0.0),
+		2 => (0.0, chroma, x),
+		3 => (0.0, x, chroma),
+		4 => (x, 0.0, chroma),
+		_ => (chroma, 0.0, x),
+	};
+
+	let m = lightness - chroma / 2.0;
+	[r, g, b].map(|channel| ((channel + m) * 255.0).round() as u8)
+}
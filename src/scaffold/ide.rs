@@ -0,0 +1,90 @@
+use crate::input::{ide::Ide, UserInput};
+use crate::utils::{create_directory, get_java_version};
+use anyhow::{Context, Result};
+use std::{fs, path::Path};
+
+/// The Gradle tasks that get an IntelliJ run configuration, with their display names.
+/// Loom adds its own `Minecraft Client` and `Minecraft Server` configs when the
+/// project is imported, so these names stay clear of those.
+const INTELLIJ_TASKS: [(&str, &str); 2] = [
+	("runClient", "Client (Gradle)"),
+	("runServer", "Server (Gradle)"),
+];
+
+pub fn create(project_dir: &Path, input: &UserInput) -> Result<()> {
+	let java_version = get_java_version(&input.minecraft_version)?;
+	let gen_sources_task = input.gradle_settings.decompiler.gen_sources_task();
+
+	for ide in &input.ides {
+		match ide {
+			Ide::IntelliJ => {
+				create_intellij(project_dir, java_version, gen_sources_task, input.gametest)?
+			}
+			Ide::VsCode => create_vscode(project_dir, gen_sources_task)?,
+		}
+	}
+
+	Ok(())
+}
+
+fn create_intellij(
+	project_dir: &Path,
+	java_version: &str,
+	gen_sources_task: &str,
+	gametest: bool,
+) -> Result<()> {
+	let idea_dir = project_dir.join(".idea");
+	let run_configs_dir = idea_dir.join("runConfigurations");
+	create_directory(&run_configs_dir)?;
+
+	let run_config = super::read_template("ide/intellij/gradle_task.xml")
+		.context("Failed to read IntelliJ run configuration template")?;
+	let gen_sources = (gen_sources_task, "Generate Sources");
+	let gametest_task = gametest.then_some(("runGametest", "Game Tests (Gradle)"));
+	for (task, name) in INTELLIJ_TASKS
+		.into_iter()
+		.chain([gen_sources])
+		.chain(gametest_task)
+	{
+		let content = run_config
+			.replace("${{ name }}", name)
+			.replace("${{ task }}", task);
+		write(&run_configs_dir.join(format!("{}.xml", task)), &content)?;
+	}
+
+	// Java 8 is still called 1.8 in IntelliJ's language levels.
+	let language_level = match java_version {
+		"8" => "JDK_1_8".to_string(),
+		version => format!("JDK_{}", version),
+	};
+	for file in ["misc.xml", "encodings.xml"] {
+		let content = super::read_template(&format!("ide/intellij/{}", file))
+			.with_context(|| format!("Failed to read IntelliJ {} template", file))?
+			.replace("${{ language_level }}", &language_level);
+		write(&idea_dir.join(file), &content)?;
+	}
+
+	Ok(())
+}
+
+/// The JDK comes from the Gradle toolchain, so nothing machine-specific ends
+/// up in the shared settings.
+fn create_vscode(project_dir: &Path, gen_sources_task: &str) -> Result<()> {
+	let vscode_dir = project_dir.join(".vscode");
+	create_directory(&vscode_dir)?;
+
+	for file in ["launch.json", "tasks.json"] {
+		let content = super::read_template(&format!("ide/vscode/{}", file))
+			.with_context(|| format!("Failed to read VS Code {} template", file))?
+			.replace("${{ gen_sources_task }}", gen_sources_task);
+		write(&vscode_dir.join(file), &content)?;
+	}
+
+	let content = super::read_template("ide/vscode/settings.json")
+		.context("Failed to read VS Code settings.json template")?;
+	write(&vscode_dir.join("settings.json"), &content)
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+	fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
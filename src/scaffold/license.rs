@@ -0,0 +1,140 @@
+use crate::input::UserInput;
+use crate::utils::current_year;
+use anyhow::{Context, Result};
+use crossterm::style::Stylize;
+use std::{fs, path::Path};
+
+/// How SPDX license texts spell the year in their copyright lines.
+const YEAR_PLACEHOLDERS: &[&str] = &["<year>", "<Year>", "<YEAR>", "[yyyy]", "[year]"];
+
+/// Writes the full license text to `LICENSE`, which the jar task bundles.
+/// Expressions combining several licenses are left for the user to write.
+pub fn create(project_dir: &Path, input: &UserInput) -> Result<()> {
+	let year = current_year().to_string();
+	let author = input.authors.join(", ");
+
+	let Some(content) = license_text(&input.license, &year, &author)? else {
+		println!(
+			"{} No LICENSE file was written for '{}'; add the license text yourself.",
+			"⚠".yellow(),
+			input.license
+		);
+		return Ok(());
+	};
+
+	let target_path = project_dir.join("LICENSE");
+	fs::write(&target_path, content)
+		.with_context(|| format!("Failed to write license to {}", target_path.display()))?;
+
+	Ok(())
+}
+
+/// Our own templates mark the year and author explicitly; SPDX texts only get
+/// the placeholders in their copyright lines filled in.
+fn license_text(license: &str, year: &str, author: &str) -> Result<Option<String>> {
+	let template_path = format!("licenses/{}", license);
+	let has_template = Path::new(env!("CARGO_MANIFEST_DIR"))
+		.join("templates")
+		.join(&template_path)
+		.is_file();
+
+	if has_template {
+		let text = super::read_template(&template_path)?
+			.replace("${{ year }}", year)
+			.replace("${{ author }}", author);
+		return Ok(Some(text));
+	}
+
+	Ok(spdx::license_id(license).map(|id| {
+		id.text()
+			.split_inclusive('\n')
+			.map(|line| fill_copyright(line, year, author))
+			.collect()
+	}))
+}
+
+/// Fills in the year of a copyright line and the holder following it, e.g.
+/// Apache's `Copyright [yyyy] [name of copyright owner]` or the GPL's
+/// `Copyright (C) <year>  <name of author>`.
+fn fill_copyright(line: &str, year: &str, holder: &str) -> String {
+	let placeholder = YEAR_PLACEHOLDERS
+		.iter()
+		.filter_map(|placeholder| {
+			line.find(placeholder)
+				.map(|start| (start, placeholder.len()))
+		})
+		.min();
+	let Some((start, len)) = placeholder.filter(|_| line.to_lowercase().contains("copyright"))
+	else {
+		return line.to_string();
+	};
+
+	let rest = &line[start + len..];
+	let holder_placeholder = rest.find(['<', '[']).and_then(|open| {
+		let close = if rest[open..].starts_with('<') {
+			'>'
+		} else {
+			']'
+		};
+		rest[open..].find(close).map(|end| (open, open + end + 1))
+	});
+	let rest = match holder_placeholder {
+		Some((open, end)) => format!("{}{}{}", &rest[..open], holder, &rest[end..]),
+		None => rest.to_string(),
+	};
+
+	format!("{}{}{}", &line[..start], year, rest)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fills_copyright_placeholders() {
+		for (line, filled) in [
+			(
+				"Copyright [yyyy] [name of copyright owner]\n",
+				"Copyright 2025 Jane Doe\n",
+			),
+			(
+				"    Copyright (C) <year>  <name of author>\n",
+				"    Copyright (C) 2025  Jane Doe\n",
+			),
+			(
+				"Copyright (c) <YEAR> <COPYRIGHT HOLDER>",
+				"Copyright (c) 2025 Jane Doe",
+			),
+			("Copyright [year]", "Copyright 2025"),
+		] {
+			assert_eq!(fill_copyright(line, "2025", "Jane Doe"), filled);
+		}
+	}
+
+	#[test]
+	fn leaves_other_lines_alone() {
+		for line in [
+			"the <year> the work was first published\n",
+			"Copyright notice and this permission notice\n",
+			"[name of copyright owner]\n",
+		] {
+			assert_eq!(fill_copyright(line, "2025", "Jane Doe"), line);
+		}
+	}
+
+	#[test]
+	fn fills_license_texts() {
+		let mit = license_text("MIT", "2025", "Jane Doe").unwrap().unwrap();
+		assert!(mit.contains("2025 Jane Doe"));
+		assert!(!mit.contains("${{"));
+
+		let apache = license_text("Apache-2.0", "2025", "Jane Doe")
+			.unwrap()
+			.unwrap();
+		assert!(apache.contains("Copyright 2025 Jane Doe"));
+
+		assert!(license_text("MIT OR Apache-2.0", "2025", "Jane Doe")
+			.unwrap()
+			.is_none());
+	}
+}
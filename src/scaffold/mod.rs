@@ -1,12 +1,19 @@
 mod assets;
+mod ci;
 mod common_files;
 pub mod git;
 mod gradle;
+mod icon;
+mod ide;
+mod license;
 mod main_class;
 mod mod_file;
 mod project_structure;
 
-use crate::input::UserInput;
+use crate::features;
+use crate::input::{gradle_dsl::GradleDsl, UserInput};
+use crate::manifest::Manifest;
+use crate::project::Project;
 use anyhow::{Context, Result};
 use std::{fs, path::Path};
 
@@ -24,6 +31,34 @@ pub fn create(input: UserInput) -> Result<()> {
 		gradle::create(project_dir, &input)?;
 		assets::create(project_dir, &input)?;
 		common_files::copy(project_dir)?;
+		license::create(project_dir, &input)?;
+		Manifest::new(&input).save(project_dir)?;
+
+		let project = Project::open(project_dir)?;
+		let info = project.mod_info()?;
+
+		if input.mixin {
+			features::mixin::add(&project, &info)?;
+		}
+
+		if input.datagen && features::datagen::is_supported(&input.minecraft_version) {
+			features::datagen::add(&project, &info)?;
+		}
+
+		if input.access_widener {
+			features::access_widener::add(&project, &info)?;
+		}
+
+		if input.junit {
+			features::junit::add(&project, &info)?;
+		}
+
+		if input.gametest {
+			features::gametest::add(&project, &info)?;
+		}
+
+		ide::create(project_dir, &input)?;
+		ci::create(project_dir, &input)?;
 
 		if input.git {
 			git::create_repo(&input).context("Failed to initialize git repository")?;
@@ -48,3 +83,18 @@ pub(crate) fn read_template(template_path: &str) -> Result<String> {
 	fs::read_to_string(&full_path)
 		.with_context(|| format!("Failed to read template file at {}", full_path.display()))
 }
+
+/// Reads a build script snippet written in the given DSL.
+pub(crate) fn read_snippet(gradle_dsl: GradleDsl, name: &str) -> Result<String> {
+	let extension = match gradle_dsl {
+		GradleDsl::Groovy => "gradle",
+		GradleDsl::Kotlin => "gradle.kts",
+	};
+
+	read_template(&format!(
+		"gradle/dsl/{}/{}.{}",
+		gradle_dsl.as_str(),
+		name,
+		extension
+	))
+}
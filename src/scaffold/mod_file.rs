@@ -1,27 +1,38 @@
 use crate::input::UserInput;
-use crate::utils::{get_java_version, sanitize_mod_id};
-use anyhow::{Context, Result};
-use std::{fs, path::Path};
+use crate::project::mod_json::{self, ModJson};
+use anyhow::{anyhow, Context, Result};
+use indexmap::IndexMap;
+use std::path::Path;
 
 pub fn create(project_dir: &Path, input: &UserInput) -> Result<()> {
-	let template_content = super::read_template("fabric/fabric.mod.json")
-		.context("Failed to read fabric.mod.json template")?;
+	spdx::Expression::parse(&input.license).map_err(|e| {
+		anyhow!(
+			"'{}' is not a valid SPDX license expression: {}",
+			input.license,
+			e.reason
+		)
+	})?;
 
-	let mod_id = sanitize_mod_id(&input.location.mod_name);
-	let replaced_content = template_content
-		.replace("${MAIN_CLASS}", &input.location.mod_name)
-		.replace("${MOD_ID}", &mod_id)
-		.replace("${PACKAGE}", &input.maven_group)
-		.replace("${VERSION}", &input.version)
-		.replace("${MOD_NAME}", &input.location.mod_name)
-		.replace("${DESCRIPTION}", &input.description)
-		.replace("${AUTHOR}", &input.author)
-		.replace("${LICENSE}", &input.license)
-		.replace("${MINECRAFT_VERSION}", &input.minecraft_version)
-		.replace(
-			"${JAVA_VERSION}",
-			&get_java_version(&input.minecraft_version)?,
-		);
+	let mod_id = input.mod_id();
+	let main_class = format!("{}.{}", input.package(), input.class_name());
+
+	let metadata = ModJson {
+		description: input.description.clone(),
+		authors: input.authors.clone(),
+		contributors: input.contributors.clone(),
+		contact: input.contact.clone(),
+		license: Some(input.license.clone()),
+		icon: Some(format!("assets/{}/icon.png", mod_id)),
+		entrypoints: IndexMap::from([("main".to_string(), vec![main_class])]),
+		// Listed here to keep its place; `features::mixin` writes the config.
+		mixins: input
+			.mixin
+			.then(|| format!("{}.mixins.json", mod_id))
+			.into_iter()
+			.collect(),
+		depends: input.dependencies()?,
+		..ModJson::new(mod_id, &input.version, &input.location.mod_name)
+	};
 
 	let target_path = project_dir
 		.join("src")
@@ -29,8 +40,5 @@ pub fn create(project_dir: &Path, input: &UserInput) -> Result<()> {
 		.join("resources")
 		.join("fabric.mod.json");
 
-	fs::write(&target_path, replaced_content)
-		.with_context(|| format!("Failed to write mod file to {}", target_path.display()))?;
-
-	Ok(())
+	mod_json::save(&target_path, &metadata).context("Failed to write mod file")
 }
@@ -15,7 +15,7 @@ pub fn create(project_dir: &Path, input: &UserInput) -> Result<()> {
 		create_directory(&project_dir.join(dir))?;
 	}
 
-	let package_path = input.maven_group.replace('.', "/");
+	let package_path = input.package().replace('.', "/");
 	let main_class_path = project_dir
 		.join("src")
 		.join("main")
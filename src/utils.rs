@@ -1,6 +1,13 @@
-use anyhow::{Context, Result};
-use semver::Version;
-use std::{fs, path::Path};
+use anyhow::{bail, Context, Result};
+use semver::{BuildMetadata, Prerelease, Version};
+use std::{
+	fs,
+	path::Path,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Fabric Loader rejects mod IDs longer than this.
+pub const MAX_MOD_ID_LENGTH: usize = 64;
 
 pub fn sanitize_mod_id(name: &str) -> String {
 	name.to_lowercase()
@@ -9,13 +16,123 @@ pub fn sanitize_mod_id(name: &str) -> String {
 		.collect()
 }
 
+/// Reserved words and literals that can't be used as Java identifiers.
+const JAVA_KEYWORDS: &[&str] = &[
+	"_",
+	"abstract",
+	"assert",
+	"boolean",
+	"break",
+	"byte",
+	"case",
+	"catch",
+	"char",
+	"class",
+	"const",
+	"continue",
+	"default",
+	"do",
+	"double",
+	"else",
+	"enum",
+	"extends",
+	"false",
+	"final",
+	"finally",
+	"float",
+	"for",
+	"goto",
+	"if",
+	"implements",
+	"import",
+	"instanceof",
+	"int",
+	"interface",
+	"long",
+	"native",
+	"new",
+	"null",
+	"package",
+	"private",
+	"protected",
+	"public",
+	"return",
+	"short",
+	"static",
+	"strictfp",
+	"super",
+	"switch",
+	"synchronized",
+	"this",
+	"throw",
+	"throws",
+	"transient",
+	"true",
+	"try",
+	"void",
+	"volatile",
+	"while",
+];
+
+/// Whether `name` can be used as a Java identifier. Only ASCII is accepted, so
+/// generated files stay portable across file systems.
+pub fn is_java_identifier(name: &str) -> bool {
+	let mut chars = name.chars();
+	let starts_legally = chars
+		.next()
+		.is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$');
+
+	starts_legally
+		&& chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+		&& !JAVA_KEYWORDS.contains(&name)
+}
+
 pub fn create_directory(path: &Path) -> Result<()> {
 	fs::create_dir_all(path)
 		.with_context(|| format!("Failed to create directory: {}", path.display()))
 }
 
+/// Parses a version that may omit its patch component, as Minecraft releases
+/// like `1.21` (and the Yarn builds for them, `1.21+build.9`) do.
+pub fn parse_version(version: &str) -> Option<Version> {
+	if let Ok(parsed) = Version::parse(version) {
+		return Some(parsed);
+	}
+
+	let core_end = version.find(['-', '+']).unwrap_or(version.len());
+	let (core, suffix) = version.split_at(core_end);
+	if core.split('.').count() != 2 {
+		return None;
+	}
+
+	Version::parse(&format!("{}.0{}", core, suffix)).ok()
+}
+
+pub fn current_year() -> i64 {
+	let days = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs() / 86_400)
+		.unwrap_or_default() as i64;
+
+	year_from_days(days)
+}
+
+/// The year of the day that is `days` days after 1970-01-01; see
+/// https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn year_from_days(days: i64) -> i64 {
+	let z = days + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z.rem_euclid(146_097);
+	let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+
+	yoe + era * 400 + i64::from(month <= 2)
+}
+
 pub fn get_java_version(minecraft_version: &str) -> Result<&'static str> {
-	let version = Version::parse(minecraft_version).unwrap();
+	let version = release_version(minecraft_version)?;
 
 	Ok(if version >= Version::parse("1.20.5").unwrap() {
 		"21"
@@ -25,3 +142,274 @@ pub fn get_java_version(minecraft_version: &str) -> Result<&'static str> {
 		"8"
 	})
 }
+
+/// The Loom and Gradle versions to use from each Minecraft release onwards,
+/// oldest first, following Fabric's example mod.
+const TOOLCHAINS: &[(&str, &str, &str)] = &[
+	("1.14.0", "1.2-SNAPSHOT", "8.1.1"),
+	("1.20.0", "1.3-SNAPSHOT", "8.3"),
+	("1.20.2", "1.4-SNAPSHOT", "8.4"),
+	("1.20.4", "1.5-SNAPSHOT", "8.6"),
+	("1.20.5", "1.6-SNAPSHOT", "8.7"),
+	("1.21.0", "1.7-SNAPSHOT", "8.9"),
+	("1.21.2", "1.8-SNAPSHOT", "8.10.2"),
+	("1.21.4", "1.9-SNAPSHOT", "8.12"),
+	("1.21.5", "1.10-SNAPSHOT", "8.14"),
+	("1.21.9", "1.11-SNAPSHOT", "8.14.3"),
+	("1.21.11", "1.14-SNAPSHOT", "9.2.1"),
+];
+/// The first release the toolchain table doesn't cover.
+const UNKNOWN_TOOLCHAIN: &str = "1.22.0";
+
+pub fn get_loom_version(minecraft_version: &str) -> Result<&'static str> {
+	toolchain(minecraft_version).map(|(loom, _)| loom)
+}
+
+pub fn get_gradle_version(minecraft_version: &str) -> Result<&'static str> {
+	toolchain(minecraft_version).map(|(_, gradle)| gradle)
+}
+
+/// Whether projects can be generated for the Minecraft version.
+pub fn is_supported_minecraft_version(minecraft_version: &str) -> bool {
+	toolchain(minecraft_version).is_ok()
+}
+
+fn toolchain(minecraft_version: &str) -> Result<(&'static str, &'static str)> {
+	let version = release_version(minecraft_version)?;
+
+	if version < Version::parse("1.14.0").unwrap() {
+		bail!("Fabric doesn't support Minecraft {}", minecraft_version);
+	}
+	if version >= Version::parse(UNKNOWN_TOOLCHAIN).unwrap() {
+		bail!(
+			"The Loom and Gradle versions for Minecraft {} aren't known yet; update modweave",
+			minecraft_version
+		);
+	}
+
+	Ok(TOOLCHAINS
+		.iter()
+		.rev()
+		.find(|(from, _, _)| version >= Version::parse(from).unwrap())
+		.map(|&(_, loom, gradle)| (loom, gradle))
+		.expect("versions from 1.14 on have a toolchain"))
+}
+
+/// The year and week a weekly snapshot such as `24w14a` was named after.
+type SnapshotWeek = (u32, u32);
+
+/// The first and last weekly snapshot leading up to each release since 1.14.
+const SNAPSHOTS: &[(SnapshotWeek, SnapshotWeek, &str)] = &[
+	((18, 43), (19, 14), "1.14.0"),
+	((19, 34), (19, 46), "1.15.0"),
+	((20, 6), (20, 22), "1.16.0"),
+	((20, 27), (20, 30), "1.16.2"),
+	((20, 45), (21, 20), "1.17.0"),
+	((21, 37), (21, 44), "1.18.0"),
+	((22, 11), (22, 19), "1.19.0"),
+	((22, 24), (22, 24), "1.19.1"),
+	((22, 42), (22, 46), "1.19.3"),
+	((23, 3), (23, 7), "1.19.4"),
+	((23, 12), (23, 18), "1.20.0"),
+	((23, 31), (23, 35), "1.20.2"),
+	((23, 40), (23, 46), "1.20.3"),
+	((23, 51), (24, 14), "1.20.5"),
+	((24, 18), (24, 21), "1.21.0"),
+	((24, 33), (24, 40), "1.21.2"),
+	((24, 44), (24, 46), "1.21.4"),
+	((25, 2), (25, 10), "1.21.5"),
+	((25, 15), (25, 21), "1.21.6"),
+	((25, 31), (25, 37), "1.21.9"),
+	((25, 41), (25, 46), "1.21.11"),
+];
+
+/// The release a Minecraft version belongs to, so pre-releases and release
+/// candidates such as `1.20.5-pre1` count as `1.20.5`, and weekly snapshots
+/// such as `24w14a` as the release they lead up to.
+pub fn release_version(minecraft_version: &str) -> Result<Version> {
+	if let Some(week) = snapshot_week(minecraft_version) {
+		let release = SNAPSHOTS
+			.iter()
+			.find(|(first, last, _)| (*first..=*last).contains(&week))
+			.map(|(_, _, release)| *release)
+			.with_context(|| format!("Unknown Minecraft snapshot: {}", minecraft_version))?;
+		return Ok(Version::parse(release).unwrap());
+	}
+
+	let mut version = parse_version(minecraft_version)
+		.with_context(|| format!("Invalid Minecraft version: {}", minecraft_version))?;
+	version.pre = Prerelease::EMPTY;
+	version.build = BuildMetadata::EMPTY;
+
+	Ok(version)
+}
+
+/// The Minecraft version as Fabric Loader compares it in `fabric.mod.json`
+/// ranges: snapshots, pre-releases and release candidates are turned into
+/// semver pre-releases of their release, e.g. `24w14a` into
+/// `1.20.5-alpha.24.14.a` and `1.20.5-pre1` into `1.20.5-beta.1`.
+pub fn loader_minecraft_version(minecraft_version: &str) -> String {
+	if let Some((year, week)) = snapshot_week(minecraft_version) {
+		if let Ok(release) = release_version(minecraft_version) {
+			let letter = &minecraft_version[minecraft_version.len() - 1..];
+			return format!("{}-alpha.{}.{}.{}", release, year, week, letter);
+		}
+	}
+
+	let unstable = minecraft_version
+		.split_once('-')
+		.and_then(|(release, suffix)| {
+			let (kind, number) = if let Some(number) = suffix.strip_prefix("pre") {
+				("beta", number)
+			} else {
+				("rc", suffix.strip_prefix("rc")?)
+			};
+			number
+				.parse::<u32>()
+				.ok()
+				.map(|number| format!("{}-{}.{}", release, kind, number))
+		});
+	unstable.unwrap_or_else(|| minecraft_version.to_string())
+}
+
+pub fn is_weekly_snapshot(minecraft_version: &str) -> bool {
+	snapshot_week(minecraft_version).is_some()
+}
+
+fn snapshot_week(minecraft_version: &str) -> Option<SnapshotWeek> {
+	let (year, rest) = minecraft_version.split_once('w')?;
+	let week = rest.get(..2)?;
+	let letter = rest.get(2..)?;
+	if year.len() != 2 || letter.len() != 1 || !letter.chars().all(|c| c.is_ascii_lowercase()) {
+		return None;
+	}
+
+	Some((year.parse().ok()?, week.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn years_change_on_new_year() {
+		for (days, year) in [
+			(0, 1970),
+			(364, 1970),
+			(365, 1971),
+			(-1, 1969),
+			// 2000-02-29, 2000-12-31 and 2001-01-01; 2000 is a leap year.
+			(11_016, 2000),
+			(11_322, 2000),
+			(11_323, 2001),
+			// 2100-01-01, 2100-12-31 and 2101-01-01; 2100 isn't a leap year.
+			(47_482, 2100),
+			(47_846, 2100),
+			(47_847, 2101),
+			// 2024-12-31 and 2025-01-01, after a leap day.
+			(20_088, 2024),
+			(20_089, 2025),
+		] {
+			assert_eq!(year_from_days(days), year, "{}", days);
+		}
+	}
+
+	#[test]
+	fn java_identifiers() {
+		for (name, valid) in [
+			("BetterCombat", true),
+			("_internal", true),
+			("$proxy", true),
+			("mod2", true),
+			("2mod", false),
+			("my-mod", false),
+			("my mod", false),
+			("class", false),
+			("_", false),
+			("", false),
+			("café", false),
+		] {
+			assert_eq!(is_java_identifier(name), valid, "{}", name);
+		}
+	}
+
+	#[test]
+	fn java_version_of_pre_releases_matches_their_release() {
+		for (minecraft, java) in [
+			("1.16.5", "8"),
+			("1.17-pre1", "17"),
+			("1.17", "17"),
+			("1.20.4", "17"),
+			("1.20.5-pre1", "21"),
+			("1.20.5-rc1", "21"),
+			("1.20.5", "21"),
+		] {
+			assert_eq!(get_java_version(minecraft).unwrap(), java, "{}", minecraft);
+		}
+	}
+
+	#[test]
+	fn toolchain_follows_minecraft_version() {
+		for (minecraft, loom, gradle) in [
+			("1.16.5", "1.2-SNAPSHOT", "8.1.1"),
+			("1.20.1", "1.3-SNAPSHOT", "8.3"),
+			("1.20.5-pre1", "1.6-SNAPSHOT", "8.7"),
+			("1.21", "1.7-SNAPSHOT", "8.9"),
+			("1.21.1", "1.7-SNAPSHOT", "8.9"),
+			("1.21.4", "1.9-SNAPSHOT", "8.12"),
+			("1.21.11-rc1", "1.14-SNAPSHOT", "9.2.1"),
+		] {
+			assert_eq!(get_loom_version(minecraft).unwrap(), loom, "{}", minecraft);
+			assert_eq!(
+				get_gradle_version(minecraft).unwrap(),
+				gradle,
+				"{}",
+				minecraft
+			);
+		}
+	}
+
+	#[test]
+	fn snapshots_count_as_their_release() {
+		for (minecraft, release) in [
+			("18w43a", "1.14.0"),
+			("19w14b", "1.14.0"),
+			("20w30a", "1.16.2"),
+			("21w20a", "1.17.0"),
+			("23w51b", "1.20.5"),
+			("24w14a", "1.20.5"),
+			("25w46a", "1.21.11"),
+		] {
+			assert_eq!(
+				release_version(minecraft).unwrap(),
+				Version::parse(release).unwrap(),
+				"{}",
+				minecraft
+			);
+		}
+		assert_eq!(get_java_version("24w14a").unwrap(), "21");
+		assert_eq!(get_loom_version("24w14a").unwrap(), "1.6-SNAPSHOT");
+	}
+
+	#[test]
+	fn loader_versions_are_semver() {
+		for (minecraft, loader) in [
+			("1.21", "1.21"),
+			("1.20.5", "1.20.5"),
+			("1.20.5-pre1", "1.20.5-beta.1"),
+			("1.20.5-rc2", "1.20.5-rc.2"),
+			("24w14a", "1.20.5-alpha.24.14.a"),
+			("23w51b", "1.20.5-alpha.23.51.b"),
+			("24w03a", "1.20.5-alpha.24.3.a"),
+		] {
+			assert_eq!(loader_minecraft_version(minecraft), loader);
+		}
+	}
+
+	#[test]
+	fn toolchain_rejects_unknown_versions() {
+		for minecraft in ["1.13.2", "1.22", "26.1", "18w30a", "24w30a", "24w14potato"] {
+			assert!(get_loom_version(minecraft).is_err(), "{}", minecraft);
+		}
+	}
+}
@@ -1,6 +1,6 @@
+use crate::utils::parse_version;
 use anyhow::{Context, Result};
 use reqwest::blocking::Client;
-use semver::Version;
 use serde::Deserialize;
 use std::cmp::Reverse;
 
@@ -21,7 +21,6 @@ struct YarnVersion {
 	#[serde(rename = "gameVersion")]
 	game_version: String,
 	version: String,
-	stable: bool,
 }
 
 #[derive(Deserialize, Debug)]
@@ -30,15 +29,18 @@ struct FabricApiVersion {
 	game_versions: Vec<String>,
 }
 
+#[derive(Deserialize, Debug)]
+struct ModrinthVersion {
+	version_number: String,
+}
+
 pub struct VersionManager {
 	client: Client,
 }
 
 pub struct VersionData {
 	pub minecraft: Vec<(String, bool)>, // (version, stable)
-	pub yarn: Vec<(String, bool)>,      // (version, stable)
 	pub loader: Vec<(String, bool)>,    // (version, stable)
-	pub fabric_api: Vec<String>,        // Fabric API doesn't have stability info
 }
 
 impl VersionManager {
@@ -51,9 +53,7 @@ impl VersionManager {
 	pub fn fetch_all_versions(&self) -> Result<VersionData> {
 		Ok(VersionData {
 			minecraft: self.fetch_minecraft_versions()?,
-			yarn: self.fetch_yarn_versions()?,
 			loader: self.fetch_loader_versions()?,
-			fabric_api: self.fetch_fabric_api_versions()?,
 		})
 	}
 
@@ -72,21 +72,6 @@ impl VersionManager {
 			.collect())
 	}
 
-	fn fetch_yarn_versions(&self) -> Result<Vec<(String, bool)>> {
-		let versions: Vec<YarnVersion> = self
-			.client
-			.get("https://meta.fabricmc.net/v2/versions/yarn")
-			.send()
-			.context("Failed to fetch Yarn versions")?
-			.json()
-			.context("Failed to parse Yarn versions")?;
-
-		Ok(versions
-			.into_iter()
-			.map(|v| (v.version, v.stable))
-			.collect())
-	}
-
 	fn fetch_loader_versions(&self) -> Result<Vec<(String, bool)>> {
 		let versions: Vec<LoaderVersion> = self
 			.client
@@ -102,16 +87,12 @@ impl VersionManager {
 			.collect())
 	}
 
-	fn fetch_fabric_api_versions(&self) -> Result<Vec<String>> {
-		let versions: Vec<FabricApiVersion> = self
-			.client
-			.get("https://api.modrinth.com/v2/project/P7dR8mSH/version")
-			.send()
-			.context("Failed to fetch Fabric API versions")?
-			.json()
-			.context("Failed to parse Fabric API versions")?;
-
-		Ok(self.sort_versions(versions.into_iter().map(|v| v.version_number)))
+	pub fn get_latest_loader_version(&self) -> Result<String> {
+		self.fetch_loader_versions()?
+			.into_iter()
+			.find(|(_, stable)| *stable)
+			.map(|(v, _)| v)
+			.context("No stable Fabric Loader version found")
 	}
 
 	pub fn get_compatible_yarn_versions(&self, minecraft_version: &str) -> Result<Vec<String>> {
@@ -153,12 +134,47 @@ impl VersionManager {
 		Ok(self.sort_versions(compatible_versions.into_iter()))
 	}
 
+	/// Modrinth lists versions newest first, so the first Fabric build for the
+	/// Minecraft version is the latest one.
+	pub fn get_latest_modrinth_version(
+		&self,
+		slug: &str,
+		minecraft_version: &str,
+	) -> Result<String> {
+		let versions: Vec<ModrinthVersion> = self
+			.client
+			.get(format!(
+				"https://api.modrinth.com/v2/project/{}/version",
+				slug
+			))
+			.query(&[
+				("loaders", "[\"fabric\"]".to_string()),
+				("game_versions", format!("[\"{}\"]", minecraft_version)),
+			])
+			.send()
+			.and_then(|response| response.error_for_status())
+			.with_context(|| format!("Failed to fetch versions of {} from Modrinth", slug))?
+			.json()
+			.with_context(|| format!("Failed to parse versions of {} from Modrinth", slug))?;
+
+		versions
+			.into_iter()
+			.next()
+			.map(|v| v.version_number)
+			.with_context(|| {
+				format!(
+					"{} has no Fabric release for Minecraft {} on Modrinth",
+					slug, minecraft_version
+				)
+			})
+	}
+
 	fn sort_versions<I>(&self, versions: I) -> Vec<String>
 	where
 		I: Iterator<Item = String>,
 	{
 		let mut sorted: Vec<_> = versions
-			.filter_map(|v| Version::parse(&v).ok().map(|parsed| (v, parsed)))
+			.filter_map(|v| parse_version(&v).map(|parsed| (v, parsed)))
 			.collect();
 
 		sorted.sort_by_key(|(_, v)| Reverse(v.clone()));
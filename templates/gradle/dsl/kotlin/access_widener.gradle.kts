@@ -0,0 +1,3 @@
+loom {
+    accessWidenerPath.set(file("src/main/resources/${{ access_widener }}"))
+}
\ No newline at end of file
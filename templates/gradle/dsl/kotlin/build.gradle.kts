@@ -1,5 +1,5 @@
 plugins {
-    id("fabric-loom") version "1.7-SNAPSHOT"
+    ${{ loom_plugin }}
     id("maven-publish")
 }
 
@@ -11,23 +11,14 @@ repositories {
 }
 
 dependencies {
-    minecraft("com.mojang:minecraft:${project.property("minecraft_version")}")
-    mappings("net.fabricmc:yarn:${project.property("yarn_mappings")}:v2")
-    modImplementation("net.fabricmc:fabric-loader:${project.property("fabric_loader_version")}")
+    minecraft(${{ minecraft_dependency }})
+    mappings(${{ mappings_dependency }})
+    modImplementation(${{ loader_dependency }})
 
-    modImplementation("net.fabricmc.fabric-api:fabric-api:${project.property("fabric_api_version")}")
+    modImplementation(${{ fabric_api_dependency }})
 }
 
 tasks {
-    processResources {
-        inputs.property("version", project.version)
-        filteringCharset = "UTF-8"
-
-        filesMatching("fabric.mod.json") {
-            expand(mutableMapOf("version" to project.version))
-        }
-    }
-
     jar {
         from("LICENSE") {
             rename { "${it}_${project.property("archivesBaseName")}" }
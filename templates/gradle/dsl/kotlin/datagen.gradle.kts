@@ -0,0 +1,3 @@
+fabricApi {
+    configureDataGeneration()
+}
\ No newline at end of file
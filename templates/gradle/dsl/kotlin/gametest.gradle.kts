@@ -0,0 +1,26 @@
+val gametest: SourceSet by sourceSets.creating {
+    compileClasspath += sourceSets.main.get().compileClasspath + sourceSets.main.get().output
+    runtimeClasspath += sourceSets.main.get().runtimeClasspath + sourceSets.main.get().output
+}
+
+loom {
+    mods {
+        register("${{ mod_id }}") {
+            sourceSet(sourceSets.main.get())
+        }
+        register("${{ gametest_id }}") {
+            sourceSet(gametest)
+        }
+    }
+
+    runs {
+        register("gametest") {
+            server()
+            configName = "Game Test"
+            source(gametest)
+            vmArg("-Dfabric-api.gametest")
+            vmArg("-Dfabric-api.gametest.report-file=${layout.buildDirectory.file("junit.xml").get().asFile}")
+            runDir("build/gametest")
+        }
+    }
+}
\ No newline at end of file
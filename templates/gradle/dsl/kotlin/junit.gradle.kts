@@ -0,0 +1,3 @@
+tasks.test {
+    useJUnitPlatform()
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+publishMods {
+    file.set(tasks.remapJar.get().archiveFile)
+    changelog.set("Release ${project.version}")
+    type.set(STABLE)
+    modLoaders.add("fabric")
+
+${{ platforms }}
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+    curseforge {
+        projectId.set("${{ project_id }}")
+        accessToken.set(providers.environmentVariable("CURSEFORGE_TOKEN"))
+        minecraftVersions.addAll(${{ minecraft_versions }})
+        requires("fabric-api")
+    }
\ No newline at end of file
@@ -0,0 +1,6 @@
+    modrinth {
+        projectId.set("${{ project_id }}")
+        accessToken.set(providers.environmentVariable("MODRINTH_TOKEN"))
+        minecraftVersions.addAll(${{ minecraft_versions }})
+        requires("fabric-api")
+    }
\ No newline at end of file